@@ -0,0 +1,92 @@
+//! # Block Text
+//!
+//! Renders text as large block letters, three terminal rows tall, using the Unicode
+//! full and half block characters. Used for the project name header on the main screen.
+//!
+//! Letters are rendered in uppercase. Supported characters are `A-Z`, `0-9`, space, `-`,
+//! `_` and `.`, anything else is rendered as a `?`.
+
+/// Height of the rendered text in rows
+pub const HEIGHT: u16 = 3;
+
+/// Width of a single glyph in columns, glyphs are separated by one column
+const GLYPH_WIDTH: usize = 3;
+
+/// Gets the glyph rows for a character
+fn glyph(c: char) -> [&'static str; 3] {
+    match c.to_ascii_uppercase() {
+        'A' => ["█▀█", "█▀█", "▀ ▀"],
+        'B' => ["█▀▄", "█▀▄", "▀▀ "],
+        'C' => ["█▀▀", "█  ", "▀▀▀"],
+        'D' => ["█▀▄", "█ █", "▀▀ "],
+        'E' => ["█▀▀", "█▀▀", "▀▀▀"],
+        'F' => ["█▀▀", "█▀ ", "▀  "],
+        'G' => ["█▀▀", "█ █", "▀▀▀"],
+        'H' => ["█ █", "█▀█", "▀ ▀"],
+        'I' => ["▀█▀", " █ ", "▀▀▀"],
+        'J' => ["  █", "  █", "▀▀ "],
+        'K' => ["█ █", "█▀▄", "▀ ▀"],
+        'L' => ["█  ", "█  ", "▀▀▀"],
+        'M' => ["█▄█", "█ █", "▀ ▀"],
+        'N' => ["█▀█", "█ █", "▀ ▀"],
+        'O' | '0' => ["█▀█", "█ █", "▀▀▀"],
+        'P' => ["█▀█", "█▀▀", "▀  "],
+        'Q' => ["█▀█", "█ █", "▀▀█"],
+        'R' => ["█▀█", "█▀▄", "▀ ▀"],
+        'S' | '5' => ["█▀▀", "▀▀█", "▀▀▀"],
+        'T' => ["▀█▀", " █ ", " ▀ "],
+        'U' => ["█ █", "█ █", "▀▀▀"],
+        'V' => ["█ █", "█ █", " ▀ "],
+        'W' => ["█ █", "█▄█", "▀ ▀"],
+        'X' => ["█ █", "▄▀▄", "▀ ▀"],
+        'Y' => ["█ █", "▀▄▀", " ▀ "],
+        'Z' => ["▀▀█", "▄▀ ", "▀▀▀"],
+        '1' => ["▄█ ", " █ ", "▀▀▀"],
+        '2' => ["▀▀█", "█▀▀", "▀▀▀"],
+        '3' => ["▀▀█", " ▀█", "▀▀▀"],
+        '4' => ["█ █", "▀▀█", "  ▀"],
+        '6' => ["█▀▀", "█▀█", "▀▀▀"],
+        '7' => ["▀▀█", "  █", "  ▀"],
+        '8' => ["█▀█", "█▀█", "▀▀▀"],
+        '9' => ["█▀█", "▀▀█", "▀▀▀"],
+        ' ' => ["   ", "   ", "   "],
+        '-' => ["   ", "▀▀▀", "   "],
+        '_' => ["   ", "   ", "▀▀▀"],
+        '.' => ["   ", "   ", " ▀ "],
+        _ => ["▀▀█", " ▀ ", " ▀ "],
+    }
+}
+
+/// Gets the width in columns of `text` rendered as block letters
+pub fn width(text: &str) -> u16 {
+    let glyphs = text.chars().count();
+    (glyphs * (GLYPH_WIDTH + 1)).saturating_sub(1) as u16
+}
+
+/// Renders `text` as block letters, one string per row
+pub fn render(text: &str) -> [String; 3] {
+    let mut rows = [String::new(), String::new(), String::new()];
+    for (i, c) in text.chars().enumerate() {
+        for (row, glyph_row) in rows.iter_mut().zip(glyph(c)) {
+            if i > 0 {
+                row.push(' ');
+            }
+            row.push_str(glyph_row);
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_rows_of_matching_width() {
+        let rows = render("rext-1");
+        assert_eq!(rows[0], "█▀█ █▀▀ █ █ ▀█▀     ▄█ ");
+        for row in &rows {
+            assert_eq!(row.chars().count() as u16, width("rext-1"));
+        }
+    }
+}
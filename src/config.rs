@@ -17,12 +17,20 @@
 //!
 //! ### User Directory (`~/.rext/`)
 //! - `rext_tui.toml` - User's custom config (overrides embedded default)
-//! - `current_theme.toml` - User's selected theme
-//! - `current_localization.toml` - User's selected language
+//! - `rext_tui.yaml` - User's custom config in YAML, with the `yaml-config` feature.
+//!   Takes precedence over `rext_tui.toml` when both exist
+//! - `preferences.toml` - User's selected theme and language
+//! - `endpoint_history.toml` - Recently created API endpoint names of each project
+//! - `session.toml` - Theme, language and open dialog of the last session, restored on launch
+//! - `debug.log` - Debug log, written when `RUST_LOG` is set
 //!
 //! ### Main Config Format
 //!
 //! ```toml
+//! # Config format version, see SUPPORTED_CONFIG_VERSION
+//! [meta]
+//! version = 1
+//!
 //! # Theme definitions with RGB color values
 //! [themes.rust]
 //! text = { r = 204, g = 205, b = 204 }
@@ -33,6 +41,10 @@
 //! [localization.en]
 //! language = "en"
 //! display = "English"
+//!
+//! # Optional user metadata, ignored by the TUI
+//! [user_meta]
+//! maintainer = "Alice"
 //! ```
 //!
 //! ## Usage
@@ -47,7 +59,7 @@
 //! let theme_name = load_current_theme().unwrap_or_else(|_| "rust".to_string());
 //!
 //! // Get available themes
-//! let themes = get_available_themes().unwrap();
+//! let themes = get_available_themes(None).unwrap();
 //! ```
 //!
 //! ## Error Handling
@@ -56,9 +68,10 @@
 //! This ensures the app always works even with broken user customizations.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, OnceLock, RwLock};
 
 use crate::error::RextTuiError;
 
@@ -67,6 +80,26 @@ const DEFAULT_CONFIG: &str = include_str!("../config/rext_tui.toml");
 const EN_LOCALIZATION: &str = include_str!("../localization/en.toml");
 const FR_LOCALIZATION: &str = include_str!("../localization/fr.toml");
 
+/// The loaded main configuration, shared for the rest of the session
+///
+/// A `RwLock` rather than a `OnceLock` so [`invalidate_config_cache`] can clear it.
+static CONFIG_CACHE: RwLock<Option<Arc<Config>>> = RwLock::new(None);
+
+/// Localization file contents by language code, shared for the rest of the session
+///
+/// Cleared per language with [`invalidate_localization_cache`].
+static LOCALIZATION_CACHE: LazyLock<RwLock<HashMap<String, String>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// The rext configuration directory, set by the first successful [`get_rext_config_dir`]
+static CONFIG_DIR: OnceLock<RextConfigDir> = OnceLock::new();
+
+/// Maximum number of API endpoint names kept in endpoint_history.toml
+pub const MAX_ENDPOINT_HISTORY: usize = 20;
+
+/// Maximum number of recently used themes kept in preferences.toml
+pub const MAX_RECENT_THEMES: usize = 5;
+
 /// RGB color value for theme configuration
 ///
 /// Used to define colors in theme configuration files. Each component
@@ -77,13 +110,60 @@ const FR_LOCALIZATION: &str = include_str!("../localization/fr.toml");
 /// ```toml
 /// primary = { r = 255, g = 107, b = 53 }  # Orange color
 /// ```
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Rgb {
     pub r: u8,
     pub g: u8,
     pub b: u8,
 }
 
+impl Rgb {
+    /// Linearly interpolates between two colors, `t = 0.0` gives `a` and `t = 1.0` gives `b`
+    ///
+    /// `t` is clamped to `0.0..=1.0`.
+    pub fn blend(a: Rgb, b: Rgb, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb {
+            r: mix(a.r, b.r),
+            g: mix(a.g, b.g),
+            b: mix(a.b, b.b),
+        }
+    }
+
+    /// Adds `amount` to each component, saturating at 255
+    pub fn lighten(&self, amount: u8) -> Rgb {
+        self.clone()
+            + Rgb {
+                r: amount,
+                g: amount,
+                b: amount,
+            }
+    }
+
+    /// Subtracts `amount` from each component, saturating at 0
+    pub fn darken(&self, amount: u8) -> Rgb {
+        Rgb {
+            r: self.r.saturating_sub(amount),
+            g: self.g.saturating_sub(amount),
+            b: self.b.saturating_sub(amount),
+        }
+    }
+}
+
+/// Component-wise addition, saturating at 255
+impl std::ops::Add<Rgb> for Rgb {
+    type Output = Rgb;
+
+    fn add(self, other: Rgb) -> Rgb {
+        Rgb {
+            r: self.r.saturating_add(other.r),
+            g: self.g.saturating_add(other.g),
+            b: self.b.saturating_add(other.b),
+        }
+    }
+}
+
 /// Main configuration structure loaded from config files
 ///
 /// Contains all theme definitions and available localizations for the TUI.
@@ -99,11 +179,42 @@ pub struct Rgb {
 /// [localization.en]
 /// language = "en"
 /// display = "English"
+///
+/// # Optional, ignored by the TUI
+/// [user_meta]
+/// maintainer = "Alice"
+/// team = "backend"
 /// ```
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct Config {
+    /// Config format metadata, configs without it are treated as version 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ConfigMeta>,
     pub themes: HashMap<String, Colors>,
     pub localization: HashMap<String, LocalizationConfig>,
+    /// Free-form user metadata, preserved but never interpreted by the TUI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_meta: Option<HashMap<String, toml::Value>>,
+}
+
+impl Config {
+    /// Gets the user-defined metadata from the `[user_meta]` section, if present
+    pub fn get_user_meta(&self) -> Option<&HashMap<String, toml::Value>> {
+        self.user_meta.as_ref()
+    }
+}
+
+/// The newest config format version this version of rext-tui can read
+///
+/// Tools writing to `~/.rext/` should not write a newer `[meta] version` than this.
+pub const SUPPORTED_CONFIG_VERSION: u32 = 1;
+
+/// The `[meta]` section of the main config
+///
+/// - `version`: The config format version, at most [`SUPPORTED_CONFIG_VERSION`] to be readable
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ConfigMeta {
+    pub version: u32,
 }
 
 /// Localization configuration for a specific language
@@ -114,7 +225,7 @@ pub struct Config {
 ///
 /// - `language`: The language code (e.g., "en", "fr")
 /// - `display`: The display name (e.g., "English", "French")
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct LocalizationConfig {
     pub language: String,
     pub display: String,
@@ -129,29 +240,308 @@ pub struct LocalizationConfig {
 /// - `primary`: Accent color for highlights, borders, and interactive elements
 /// - `text`: Regular text color for most content
 /// - `background`: Background color for the entire application
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Colors {
     pub primary: Rgb,
     pub text: Rgb,
     pub background: Rgb,
 }
 
-/// Stores the current theme name for the TUI in current_theme.toml
-#[derive(Deserialize, Serialize)]
-pub struct CurrentTheme {
-    pub current_theme: String,
+impl Colors {
+    /// Builds colors from a TOML table that didn't go through [`Config`] deserialization
+    ///
+    /// Each of `primary`, `text` and `background` is either an `{ r = .., g = .., b = .. }`
+    /// table or a `"#rrggbb"` string. The error names the field that couldn't be read.
+    ///
+    /// ```
+    /// use rext_tui::config::{Colors, Rgb};
+    ///
+    /// let value: toml::Value = toml::from_str(
+    ///     r##"
+    ///     primary = "#ff6b35"
+    ///     text = { r = 204, g = 205, b = 204 }
+    ///     background = "#1a1a1a"
+    ///     "##,
+    /// )
+    /// .unwrap();
+    /// let colors = Colors::from_toml_value(&value).unwrap();
+    /// assert_eq!(colors.primary, Rgb { r: 255, g: 107, b: 53 });
+    /// ```
+    pub fn from_toml_value(v: &toml::Value) -> Result<Colors, String> {
+        let table = v
+            .as_table()
+            .ok_or_else(|| "colors: expected a table".to_string())?;
+        let color = |field: &str| match table.get(field) {
+            Some(value) => rgb_from_toml_value(value).map_err(|e| format!("{}: {}", field, e)),
+            None => Err(format!("{}: missing", field)),
+        };
+        Ok(Colors {
+            primary: color("primary")?,
+            text: color("text")?,
+            background: color("background")?,
+        })
+    }
 }
 
-/// Stores the current localization for the TUI in current_localization.toml
-#[derive(Deserialize, Serialize)]
-pub struct CurrentLocalization {
-    pub current_localization: String,
+/// Reads an `{ r = .., g = .., b = .. }` table or a `"#rrggbb"` string as a color
+fn rgb_from_toml_value(value: &toml::Value) -> Result<Rgb, String> {
+    match value {
+        toml::Value::String(hex) => {
+            let digits = hex
+                .strip_prefix('#')
+                .filter(|digits| digits.len() == 6 && digits.is_ascii())
+                .ok_or_else(|| format!("expected \"#rrggbb\", got \"{}\"", hex))?;
+            let component = |range: std::ops::Range<usize>| {
+                u8::from_str_radix(&digits[range], 16)
+                    .map_err(|_| format!("invalid hex color \"{}\"", hex))
+            };
+            Ok(Rgb {
+                r: component(0..2)?,
+                g: component(2..4)?,
+                b: component(4..6)?,
+            })
+        }
+        toml::Value::Table(table) => {
+            let component = |name: &str| {
+                let component = table
+                    .get(name)
+                    .ok_or_else(|| format!("missing component {}", name))?;
+                component
+                    .as_integer()
+                    .and_then(|component| u8::try_from(component).ok())
+                    .ok_or_else(|| format!("component {} must be between 0 and 255", name))
+            };
+            Ok(Rgb {
+                r: component("r")?,
+                g: component("g")?,
+                b: component("b")?,
+            })
+        }
+        _ => Err("expected an { r, g, b } table or a \"#rrggbb\" string".to_string()),
+    }
+}
+
+/// Stores the user's selected theme and language in preferences.toml
+///
+/// # Example
+///
+/// ```toml
+/// theme = "dracula"
+/// language = "fr"
+/// recently_used_themes = ["dracula", "nord"]
+/// language_sticky_search = true
+/// telemetry_enabled = false
+/// telemetry_prompted = true
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct UserPreferences {
+    pub theme: String,
+    pub language: String,
+    /// Recently used theme names, most recent first, at most [`MAX_RECENT_THEMES`]
+    #[serde(default)]
+    pub recently_used_themes: Vec<String>,
+    /// Whether the language dialog keeps its search text between opens
+    #[serde(default = "default_language_sticky_search")]
+    pub language_sticky_search: bool,
+    /// Whether actions are recorded to the local telemetry file, see [`crate::telemetry`]
+    #[serde(default)]
+    pub telemetry_enabled: bool,
+    /// Whether the user was already asked to opt in to telemetry
+    #[serde(default)]
+    pub telemetry_prompted: bool,
+}
+
+/// Sticky language search is on unless the user turned it off
+fn default_language_sticky_search() -> bool {
+    true
+}
+
+impl Default for UserPreferences {
+    fn default() -> Self {
+        Self {
+            theme: "rust".to_string(),
+            language: "en".to_string(),
+            recently_used_themes: Vec::new(),
+            language_sticky_search: default_language_sticky_search(),
+            telemetry_enabled: false,
+            telemetry_prompted: false,
+        }
+    }
+}
+
+/// Legacy current_theme.toml format, only read to migrate to preferences.toml
+#[derive(Deserialize)]
+struct LegacyCurrentTheme {
+    current_theme: String,
+}
+
+/// Legacy current_localization.toml format, only read to migrate to preferences.toml
+#[derive(Deserialize)]
+struct LegacyCurrentLocalization {
+    current_localization: String,
+}
+
+/// Stores the recently created API endpoint names in endpoint_history.toml
+///
+/// - `projects`: The endpoint names of each project, oldest first, keyed by the project directory
+#[derive(Deserialize, Serialize, Default)]
+pub struct EndpointHistory {
+    #[serde(default)]
+    pub projects: BTreeMap<String, Vec<String>>,
+}
+
+/// A snapshot of the TUI session, saved to session.toml on exit and restored on the next launch
+///
+/// - `theme`: The active theme name
+/// - `language`: The active language code
+/// - `last_dialog`: The open dialog, as [`DialogType::as_log_str`](crate::DialogType::as_log_str)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AppSnapshot {
+    pub theme: String,
+    pub language: String,
+    pub last_dialog: String,
+}
+
+impl TryFrom<toml::Value> for AppSnapshot {
+    type Error = RextTuiError;
+
+    fn try_from(value: toml::Value) -> Result<Self, Self::Error> {
+        value.try_into().map_err(RextTuiError::ConfigError)
+    }
+}
+
+/// How serious a [`HealthIssue`] is
+///
+/// - `Error`: Something is broken, the TUI falls back to defaults or can't save
+/// - `Warning`: Something is off but the TUI works around it
+/// - `Info`: Nothing is broken, but there's something worth knowing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// A problem found by [`health_check`]
+///
+/// - `severity`: How serious the problem is
+/// - `description`: What's wrong
+/// - `suggestion`: How to fix it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthIssue {
+    pub severity: IssueSeverity,
+    pub description: String,
+    pub suggestion: String,
+}
+
+impl HealthIssue {
+    fn new(severity: IssueSeverity, description: String, suggestion: &str) -> Self {
+        Self {
+            severity,
+            description,
+            suggestion: suggestion.to_string(),
+        }
+    }
+}
+
+/// Conventional output directory for generated SeaORM entities, relative to the project
+pub const DEFAULT_SEA_ORM_OUTPUT_DIR: &str = "src/entity";
+
+/// Path of the project's SeaORM config, relative to the project directory
+pub const SEA_ORM_CONFIG_PATH: &str = ".rext/seaorm.toml";
+
+/// The project's SeaORM entity generation settings, saved to .rext/seaorm.toml
+///
+/// - `database_url`: The database the entities are generated from
+/// - `output_dir`: Where the generated entities are written
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SeaOrmConfig {
+    pub database_url: String,
+    pub output_dir: String,
+}
+
+/// The rext configuration directory (~/.rext/)
+///
+/// Returned by [`get_rext_config_dir`], which creates the directory once and caches it, so
+/// the paths of the individual config files can be derived without touching the filesystem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RextConfigDir(PathBuf);
+
+impl RextConfigDir {
+    /// Uses `path` as the config directory instead of ~/.rext/, e.g. a temporary directory in tests
+    ///
+    /// Creates the directory if it doesn't exist.
+    pub fn new(path: PathBuf) -> Result<Self, RextTuiError> {
+        fs::create_dir_all(&path).map_err(RextTuiError::WriteConfigFile)?;
+        Ok(Self(path))
+    }
+
+    /// The directory path itself
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Path of the user preferences file (preferences.toml)
+    pub fn preferences_path(&self) -> PathBuf {
+        self.0.join("preferences.toml")
+    }
+
+    /// Path of the endpoint history file (endpoint_history.toml)
+    pub fn endpoint_history_path(&self) -> PathBuf {
+        self.0.join("endpoint_history.toml")
+    }
+
+    /// Path of the session snapshot file (session.toml)
+    pub fn session_path(&self) -> PathBuf {
+        self.0.join("session.toml")
+    }
+
+    /// Path of the local telemetry file (telemetry.jsonl)
+    pub fn telemetry_path(&self) -> PathBuf {
+        self.0.join("telemetry.jsonl")
+    }
+
+    /// Path of the rext-core operation log (tui.log), see [`crate::operation_log`]
+    pub fn operation_log_path(&self) -> PathBuf {
+        self.0.join("tui.log")
+    }
+
+    /// Path of the debug log written when `RUST_LOG` is set (debug.log)
+    pub fn debug_log_path(&self) -> PathBuf {
+        self.0.join("debug.log")
+    }
+
+    /// Path of the lock file held while the TUI runs (tui.lock)
+    pub fn lock_path(&self) -> PathBuf {
+        self.0.join("tui.lock")
+    }
+
+    /// Path of the user's custom config file (rext_tui.toml)
+    pub fn user_config_path(&self) -> PathBuf {
+        self.0.join("rext_tui.toml")
+    }
+
+    /// The user's custom YAML config, rext_tui.yaml
+    #[cfg(feature = "yaml-config")]
+    pub fn user_yaml_config_path(&self) -> PathBuf {
+        self.0.join("rext_tui.yaml")
+    }
+
+    /// Directory holding the user's localization overrides
+    pub fn localization_dir(&self) -> PathBuf {
+        self.0.join("localization")
+    }
 }
 
-/// Gets the rext configuration directory path (~/.rext/)
+/// Gets the rext configuration directory (~/.rext/)
 ///
-/// Creates the directory if it doesn't exist.
-fn get_rext_config_dir() -> Result<PathBuf, RextTuiError> {
+/// Creates the directory if it doesn't exist. The directory is cached after the first
+/// successful call, later calls don't touch the filesystem.
+pub fn get_rext_config_dir() -> Result<RextConfigDir, RextTuiError> {
+    if let Some(dir) = CONFIG_DIR.get() {
+        return Ok(dir.clone());
+    }
+
     let home_dir = dirs::home_dir().ok_or_else(|| {
         RextTuiError::ReadConfigFile(std::io::Error::new(
             std::io::ErrorKind::NotFound,
@@ -166,40 +556,124 @@ fn get_rext_config_dir() -> Result<PathBuf, RextTuiError> {
         fs::create_dir_all(&rext_dir).map_err(|e| RextTuiError::WriteConfigFile(e))?;
     }
 
-    Ok(rext_dir)
-}
-
-/// Gets the path for current theme config file
-fn get_current_theme_path() -> Result<PathBuf, RextTuiError> {
-    Ok(get_rext_config_dir()?.join("current_theme.toml"))
-}
-
-/// Gets the path for current localization config file
-fn get_current_localization_path() -> Result<PathBuf, RextTuiError> {
-    Ok(get_rext_config_dir()?.join("current_localization.toml"))
-}
-
-/// Gets the path for user's custom config file
-fn get_user_config_path() -> Result<PathBuf, RextTuiError> {
-    Ok(get_rext_config_dir()?.join("rext_tui.toml"))
+    Ok(CONFIG_DIR.get_or_init(|| RextConfigDir(rext_dir)).clone())
 }
 
 /// Loads the main configuration
 ///
 /// Checks for user config in ~/.rext/rext_tui.toml first, falls back to embedded default.
+/// With the `yaml-config` feature, ~/.rext/rext_tui.yaml is checked before rext_tui.toml
+/// and wins if both exist.
 /// This ensures the app always works even if user config is missing or invalid.
 ///
+/// The config is read once and cached for the session, call [`invalidate_config_cache`]
+/// to pick up changes on the next call.
+///
 /// # Returns
 ///
-/// - `Ok(Config)`: Successfully loaded configuration
+/// - `Ok(Arc<Config>)`: Successfully loaded configuration
 /// - `Err(RextTuiError)`: Only fails if embedded config is invalid (should never happen)
-pub fn load_config() -> Result<Config, RextTuiError> {
+pub fn load_config() -> Result<Arc<Config>, RextTuiError> {
+    if let Some(config) = CONFIG_CACHE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+    {
+        return Ok(Arc::clone(config));
+    }
+
+    let config = Arc::new(read_config()?);
+    *CONFIG_CACHE.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::clone(&config));
+    Ok(config)
+}
+
+/// Clears the cached config, so the next [`load_config`] reads the config files again
+pub fn invalidate_config_cache() {
+    *CONFIG_CACHE.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Top-level keys of rext_tui.toml, other keys are ignored with a warning
+const KNOWN_CONFIG_KEYS: [&str; 4] = ["themes", "localization", "meta", "user_meta"];
+
+/// Lists the top-level keys of a TOML config that aren't in [`KNOWN_CONFIG_KEYS`],
+/// each with the known key closest to it (e.g. `("thems", "themes")`)
+fn unknown_config_keys(contents: &str) -> Vec<(String, &'static str)> {
+    let Ok(table) = toml::from_str::<toml::Table>(contents) else {
+        return Vec::new();
+    };
+    table
+        .keys()
+        .filter(|key| !KNOWN_CONFIG_KEYS.contains(&key.as_str()))
+        .map(|key| {
+            let closest = KNOWN_CONFIG_KEYS
+                .into_iter()
+                .min_by_key(|known| edit_distance(key, known))
+                .unwrap_or("themes");
+            (key.clone(), closest)
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two strings, in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Rejects a config written in a newer format than [`SUPPORTED_CONFIG_VERSION`]
+fn check_config_version(config: Config) -> Result<Config, RextTuiError> {
+    match &config.meta {
+        Some(meta) if meta.version > SUPPORTED_CONFIG_VERSION => {
+            Err(RextTuiError::UnsupportedConfigVersion {
+                found: meta.version,
+                supported: SUPPORTED_CONFIG_VERSION,
+            })
+        }
+        _ => Ok(config),
+    }
+}
+
+/// Reads the main configuration from the user config or the embedded default
+fn read_config() -> Result<Config, RextTuiError> {
+    read_config_in(get_rext_config_dir().ok().as_ref())
+}
+
+/// Reads the main configuration from the user config in `config_dir`, or the embedded
+/// default if there's no config directory
+fn read_config_in(config_dir: Option<&RextConfigDir>) -> Result<Config, RextTuiError> {
+    // A user YAML config takes precedence over the TOML one
+    #[cfg(feature = "yaml-config")]
+    if let Some(config_dir) = config_dir {
+        if let Ok(contents) = fs::read_to_string(config_dir.user_yaml_config_path()) {
+            if let Ok(config) = serde_yaml::from_str::<Config>(&contents) {
+                return check_config_version(config);
+            }
+        }
+    }
+
     // Try to load user config first
-    if let Ok(user_config_path) = get_user_config_path() {
+    if let Some(user_config_path) = config_dir.map(|dir| dir.user_config_path()) {
         if user_config_path.exists() {
             if let Ok(contents) = fs::read_to_string(&user_config_path) {
                 if let Ok(config) = toml::from_str::<Config>(&contents) {
-                    return Ok(config);
+                    for (key, closest) in unknown_config_keys(&contents) {
+                        log::warn!(
+                            "Unknown config key '{}' in {} - did you mean '{}'?",
+                            key,
+                            user_config_path.display(),
+                            closest
+                        );
+                    }
+                    return check_config_version(config);
                 }
                 // If user config is invalid, we'll fall back to embedded default
                 // Could log a warning here in the future
@@ -213,21 +687,162 @@ pub fn load_config() -> Result<Config, RextTuiError> {
     Ok(config)
 }
 
-/// Loads the current theme name from ~/.rext/current_theme.toml
+/// Serializes a config to YAML, e.g. to export it as a starting point for rext_tui.yaml
+#[cfg(feature = "yaml-config")]
+pub fn export_config_as_yaml(config: &Config) -> Result<String, RextTuiError> {
+    serde_yaml::to_string(config).map_err(RextTuiError::YamlError)
+}
+
+/// Loads the user preferences from ~/.rext/preferences.toml
+///
+/// If preferences.toml doesn't exist yet, the legacy current_theme.toml and
+/// current_localization.toml files are merged into it.
+///
+/// # Returns
+///
+/// - `Ok(UserPreferences)`: The user's selected theme and language
+/// - `Err(RextTuiError)`: File not found, parse error, or I/O error
+pub fn load_preferences() -> Result<UserPreferences, RextTuiError> {
+    load_preferences_in(&get_rext_config_dir()?)
+}
+
+/// Saves the user preferences to ~/.rext/preferences.toml
+///
+/// # Arguments
+///
+/// * `preferences` - The preferences to save
+///
+/// # Returns
+///
+/// - `Ok(())`: Preferences successfully saved
+/// - `Err(RextTuiError)`: Serialization error or I/O error
+pub fn save_preferences(preferences: &UserPreferences) -> Result<(), RextTuiError> {
+    save_preferences_in(&get_rext_config_dir()?, preferences)
+}
+
+/// Loads the user preferences from preferences.toml in the config directory `dir`,
+/// migrating the legacy files if needed
+pub fn load_preferences_in(dir: &RextConfigDir) -> Result<UserPreferences, RextTuiError> {
+    let preferences_path = dir.preferences_path();
+    if !preferences_path.exists() {
+        return migrate_legacy_preferences(dir.path());
+    }
+
+    let contents = fs::read_to_string(&preferences_path).map_err(RextTuiError::ReadConfigFile)?;
+    toml::from_str(&contents).map_err(RextTuiError::ConfigError)
+}
+
+/// Saves the user preferences to preferences.toml in the config directory `dir`
+pub fn save_preferences_in(
+    dir: &RextConfigDir,
+    preferences: &UserPreferences,
+) -> Result<(), RextTuiError> {
+    let contents = toml::to_string(preferences).map_err(RextTuiError::SerializeError)?;
+    fs::write(dir.preferences_path(), contents).map_err(RextTuiError::WriteConfigFile)?;
+    Ok(())
+}
+
+/// Merges the legacy current_theme.toml and current_localization.toml files into preferences.toml
+///
+/// Missing legacy values fall back to [`UserPreferences::default`]. Fails with a
+/// `NotFound` error if neither legacy file exists.
+fn migrate_legacy_preferences(dir: &Path) -> Result<UserPreferences, RextTuiError> {
+    let theme_path = dir.join("current_theme.toml");
+    let localization_path = dir.join("current_localization.toml");
+    if !theme_path.exists() && !localization_path.exists() {
+        return Err(RextTuiError::ReadConfigFile(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No preferences file found",
+        )));
+    }
+
+    let mut preferences = UserPreferences::default();
+    if let Ok(contents) = fs::read_to_string(&theme_path) {
+        if let Ok(legacy) = toml::from_str::<LegacyCurrentTheme>(&contents) {
+            preferences.theme = legacy.current_theme;
+        }
+    }
+    if let Ok(contents) = fs::read_to_string(&localization_path) {
+        if let Ok(legacy) = toml::from_str::<LegacyCurrentLocalization>(&contents) {
+            preferences.language = legacy.current_localization;
+        }
+    }
+
+    let contents = toml::to_string(&preferences).map_err(RextTuiError::SerializeError)?;
+    fs::write(dir.join("preferences.toml"), contents).map_err(RextTuiError::WriteConfigFile)?;
+    Ok(preferences)
+}
+
+/// Loads the current theme name from ~/.rext/preferences.toml
 ///
 /// # Returns
 ///
 /// - `Ok(String)`: The current theme name (e.g., "rust", "dracula")
 /// - `Err(RextTuiError)`: File not found, parse error, or I/O error
 pub fn load_current_theme() -> Result<String, RextTuiError> {
-    let theme_path = get_current_theme_path()?;
-    let contents = fs::read_to_string(&theme_path).map_err(|e| RextTuiError::ReadConfigFile(e))?;
-    let theme_config: CurrentTheme =
-        toml::from_str(&contents).map_err(|e| RextTuiError::ConfigError(e))?;
-    Ok(theme_config.current_theme)
+    Ok(load_preferences()?.theme)
 }
 
-/// Saves the current theme name to ~/.rext/current_theme.toml
+/// Loads the recently used themes from ~/.rext/preferences.toml, most recent first
+pub fn load_recently_used_themes() -> Result<Vec<String>, RextTuiError> {
+    Ok(load_preferences()?.recently_used_themes)
+}
+
+/// Saves the recently used themes to ~/.rext/preferences.toml
+///
+/// Only the first [`MAX_RECENT_THEMES`] entries are kept.
+pub fn save_recently_used_themes(themes: &[String]) -> Result<(), RextTuiError> {
+    save_recently_used_themes_in(&get_rext_config_dir()?, themes)
+}
+
+/// Saves the recently used themes to preferences.toml in the config directory `dir`,
+/// see [`save_recently_used_themes`]
+pub fn save_recently_used_themes_in(
+    dir: &RextConfigDir,
+    themes: &[String],
+) -> Result<(), RextTuiError> {
+    let mut preferences = load_preferences_in(dir).unwrap_or_default();
+    preferences.recently_used_themes = themes.iter().take(MAX_RECENT_THEMES).cloned().collect();
+    save_preferences_in(dir, &preferences)
+}
+
+/// Loads whether the language dialog keeps its search text from ~/.rext/preferences.toml
+pub fn load_language_sticky_search() -> Result<bool, RextTuiError> {
+    Ok(load_preferences()?.language_sticky_search)
+}
+
+/// Saves whether the language dialog keeps its search text to ~/.rext/preferences.toml
+pub fn save_language_sticky_search(sticky: bool) -> Result<(), RextTuiError> {
+    save_language_sticky_search_in(&get_rext_config_dir()?, sticky)
+}
+
+/// Saves whether the language dialog keeps its search text to preferences.toml in the
+/// config directory `dir`
+pub fn save_language_sticky_search_in(
+    dir: &RextConfigDir,
+    sticky: bool,
+) -> Result<(), RextTuiError> {
+    let mut preferences = load_preferences_in(dir).unwrap_or_default();
+    preferences.language_sticky_search = sticky;
+    save_preferences_in(dir, &preferences)
+}
+
+/// Saves the user's telemetry choice to ~/.rext/preferences.toml, they won't be asked again
+pub fn save_telemetry_enabled(enabled: bool) -> Result<(), RextTuiError> {
+    save_telemetry_enabled_in(&get_rext_config_dir()?, enabled)
+}
+
+/// Saves the user's telemetry choice to preferences.toml in the config directory `dir`
+pub fn save_telemetry_enabled_in(dir: &RextConfigDir, enabled: bool) -> Result<(), RextTuiError> {
+    let mut preferences = load_preferences_in(dir).unwrap_or_default();
+    preferences.telemetry_enabled = enabled;
+    preferences.telemetry_prompted = true;
+    save_preferences_in(dir, &preferences)
+}
+
+/// Saves the current theme name to ~/.rext/preferences.toml
+///
+/// The file is left untouched if `theme_name` is already the saved theme.
 ///
 /// # Arguments
 ///
@@ -238,12 +853,70 @@ pub fn load_current_theme() -> Result<String, RextTuiError> {
 /// - `Ok(())`: Theme successfully saved
 /// - `Err(RextTuiError)`: Serialization error or I/O error
 pub fn save_current_theme(theme_name: &str) -> Result<(), RextTuiError> {
-    let theme_config = CurrentTheme {
-        current_theme: theme_name.to_string(),
+    save_current_theme_in(&get_rext_config_dir()?, theme_name)
+}
+
+/// Saves the current theme name to preferences.toml in the config directory `dir`,
+/// see [`save_current_theme`]
+pub fn save_current_theme_in(dir: &RextConfigDir, theme_name: &str) -> Result<(), RextTuiError> {
+    let mut preferences = match load_preferences_in(dir) {
+        Ok(preferences) if preferences.theme == theme_name => return Ok(()),
+        result => result.unwrap_or_default(),
     };
-    let contents = toml::to_string(&theme_config).map_err(|e| RextTuiError::SerializeError(e))?;
-    let theme_path = get_current_theme_path()?;
-    fs::write(&theme_path, contents).map_err(|e| RextTuiError::WriteConfigFile(e))?;
+    preferences.theme = theme_name.to_string();
+    save_preferences_in(dir, &preferences)
+}
+
+/// Saves the current theme name to ~/.rext/preferences.toml without blocking the runtime
+///
+/// Async variant of [`save_current_theme`], the file is left untouched if `theme_name`
+/// is already the saved theme.
+#[cfg(feature = "tokio")]
+pub async fn save_current_theme_async(theme_name: &str) -> Result<(), RextTuiError> {
+    save_current_theme_in_async(&get_rext_config_dir()?, theme_name).await
+}
+
+/// Saves the current theme name to preferences.toml in the config directory `dir`,
+/// see [`save_current_theme_async`]
+#[cfg(feature = "tokio")]
+pub async fn save_current_theme_in_async(
+    dir: &RextConfigDir,
+    theme_name: &str,
+) -> Result<(), RextTuiError> {
+    let mut preferences = match load_preferences_in_async(dir).await {
+        Ok(preferences) if preferences.theme == theme_name => return Ok(()),
+        result => result.unwrap_or_default(),
+    };
+    preferences.theme = theme_name.to_string();
+    save_preferences_in_async(dir, &preferences).await
+}
+
+/// Reads the user preferences from preferences.toml in the config directory `dir` with `tokio::fs`
+///
+/// Only the one-time migration of the legacy files, when preferences.toml doesn't exist
+/// yet, reads them synchronously.
+#[cfg(feature = "tokio")]
+async fn load_preferences_in_async(dir: &RextConfigDir) -> Result<UserPreferences, RextTuiError> {
+    let contents = match tokio::fs::read_to_string(dir.preferences_path()).await {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return migrate_legacy_preferences(dir.path());
+        }
+        Err(e) => return Err(RextTuiError::ReadConfigFile(e)),
+    };
+    toml::from_str(&contents).map_err(RextTuiError::ConfigError)
+}
+
+/// Writes the user preferences to preferences.toml in the config directory `dir` with `tokio::fs`
+#[cfg(feature = "tokio")]
+async fn save_preferences_in_async(
+    dir: &RextConfigDir,
+    preferences: &UserPreferences,
+) -> Result<(), RextTuiError> {
+    let contents = toml::to_string(preferences).map_err(RextTuiError::SerializeError)?;
+    tokio::fs::write(dir.preferences_path(), contents)
+        .await
+        .map_err(RextTuiError::WriteConfigFile)?;
     Ok(())
 }
 
@@ -258,8 +931,48 @@ pub fn save_current_theme(theme_name: &str) -> Result<(), RextTuiError> {
 /// - `Ok(Colors)`: The colors for the selected theme
 /// - `Err(RextTuiError)`: Theme not found or config error
 pub fn load_theme_colors(theme_name: &str) -> Result<Colors, RextTuiError> {
-    let config = load_config()?;
+    load_config().and_then(|config| theme_colors_from(theme_name, &config))
+}
+
+/// A standalone theme file, with the colors in a single `[colors]` section
+#[derive(Deserialize)]
+struct ThemeFile {
+    colors: Colors,
+}
+
+/// Loads theme colors from a TOML file outside the config, e.g. the `REXT_THEME_FILE` file
+///
+/// The file has a single `[colors]` section with the same fields as a config theme:
+///
+/// ```toml
+/// [colors]
+/// text = { r = 204, g = 205, b = 204 }
+/// primary = { r = 255, g = 107, b = 53 }
+/// background = { r = 26, g = 26, b = 26 }
+/// ```
+///
+/// # Returns
+///
+/// - `Ok(Colors)`: The colors in the file
+/// - `Err(RextTuiError)`: File not found, parse error, or I/O error
+pub fn load_theme_from_file(path: &Path) -> Result<Colors, RextTuiError> {
+    let contents = fs::read_to_string(path).map_err(RextTuiError::ReadConfigFile)?;
+    let theme_file: ThemeFile = toml::from_str(&contents)?;
+    Ok(theme_file.colors)
+}
 
+/// Gets the selected theme colors from an already loaded config
+///
+/// # Arguments
+///
+/// * `theme_name` - The name of the theme to get colors from
+/// * `config` - The config to look the theme up in
+///
+/// # Returns
+///
+/// - `Ok(Colors)`: The colors for the selected theme
+/// - `Err(RextTuiError)`: Theme not found
+pub fn theme_colors_from(theme_name: &str, config: &Config) -> Result<Colors, RextTuiError> {
     config
         .themes
         .get(theme_name)
@@ -269,33 +982,41 @@ pub fn load_theme_colors(theme_name: &str) -> Result<Colors, RextTuiError> {
 
 /// Gets the available themes from the config
 ///
+/// Themes are sorted alphabetically. When `current` names an available theme, the list
+/// is rotated so it comes first and the following themes continue in alphabetical order,
+/// wrapping around, so index 1 is always the next theme when cycling.
+///
+/// # Arguments
+///
+/// * `current` - The active theme to list first, if any
+///
 /// # Returns
 ///
 /// - `Ok(Vec<String>)`: A list of available theme names
 /// - `Err(RextTuiError)`: Config loading error
-pub fn get_available_themes() -> Result<Vec<String>, RextTuiError> {
+pub fn get_available_themes(current: Option<&str>) -> Result<Vec<String>, RextTuiError> {
     let config = load_config()?;
     let mut themes: Vec<String> = config.themes.keys().cloned().collect();
     themes.sort();
+    if let Some(index) = current.and_then(|current| themes.iter().position(|t| t == current)) {
+        themes.rotate_left(index);
+    }
     Ok(themes)
 }
 
-/// Loads the current language from ~/.rext/current_localization.toml
+/// Loads the current language from ~/.rext/preferences.toml
 ///
 /// # Returns
 ///
 /// - `Ok(String)`: The current language code (e.g., "en", "fr")
 /// - `Err(RextTuiError)`: File not found, parse error, or I/O error
 pub fn load_current_language() -> Result<String, RextTuiError> {
-    let localization_path = get_current_localization_path()?;
-    let contents =
-        fs::read_to_string(&localization_path).map_err(|e| RextTuiError::ReadConfigFile(e))?;
-    let localization_config: CurrentLocalization =
-        toml::from_str(&contents).map_err(|e| RextTuiError::ConfigError(e))?;
-    Ok(localization_config.current_localization)
+    Ok(load_preferences()?.language)
 }
 
-/// Saves the current language to ~/.rext/current_localization.toml
+/// Saves the current language to ~/.rext/preferences.toml
+///
+/// The file is left untouched if `language` is already the saved language.
 ///
 /// # Arguments
 ///
@@ -306,13 +1027,305 @@ pub fn load_current_language() -> Result<String, RextTuiError> {
 /// - `Ok(())`: Language successfully saved
 /// - `Err(RextTuiError)`: Serialization error or I/O error
 pub fn save_current_language(language: &str) -> Result<(), RextTuiError> {
-    let localization_config = CurrentLocalization {
-        current_localization: language.to_string(),
+    save_current_language_in(&get_rext_config_dir()?, language)
+}
+
+/// Saves the current language to preferences.toml in the config directory `dir`,
+/// see [`save_current_language`]
+pub fn save_current_language_in(dir: &RextConfigDir, language: &str) -> Result<(), RextTuiError> {
+    let mut preferences = match load_preferences_in(dir) {
+        Ok(preferences) if preferences.language == language => return Ok(()),
+        result => result.unwrap_or_default(),
+    };
+    preferences.language = language.to_string();
+    save_preferences_in(dir, &preferences)
+}
+
+/// Saves the current language to ~/.rext/preferences.toml without blocking the runtime
+///
+/// Async variant of [`save_current_language`], the file is left untouched if `language`
+/// is already the saved language.
+#[cfg(feature = "tokio")]
+pub async fn save_current_language_async(language: &str) -> Result<(), RextTuiError> {
+    save_current_language_in_async(&get_rext_config_dir()?, language).await
+}
+
+/// Saves the current language to preferences.toml in the config directory `dir`,
+/// see [`save_current_language_async`]
+#[cfg(feature = "tokio")]
+pub async fn save_current_language_in_async(
+    dir: &RextConfigDir,
+    language: &str,
+) -> Result<(), RextTuiError> {
+    let mut preferences = match load_preferences_in_async(dir).await {
+        Ok(preferences) if preferences.language == language => return Ok(()),
+        result => result.unwrap_or_default(),
     };
-    let contents =
-        toml::to_string(&localization_config).map_err(|e| RextTuiError::SerializeError(e))?;
-    let localization_path = get_current_localization_path()?;
-    fs::write(&localization_path, contents).map_err(|e| RextTuiError::WriteConfigFile(e))?;
+    preferences.language = language.to_string();
+    save_preferences_in_async(dir, &preferences).await
+}
+
+/// Loads the API endpoint history of the project in `project` from ~/.rext/endpoint_history.toml
+///
+/// # Returns
+///
+/// - `Ok(Vec<String>)`: The project's endpoint names, oldest first, empty if it has none yet
+/// - `Err(RextTuiError)`: File not found, parse error, or I/O error
+pub fn load_endpoint_history(project: &Path) -> Result<Vec<String>, RextTuiError> {
+    load_endpoint_history_in(&get_rext_config_dir()?, project)
+}
+
+/// Loads the API endpoint history of the project in `project` from endpoint_history.toml in
+/// the config directory `dir`
+pub fn load_endpoint_history_in(
+    dir: &RextConfigDir,
+    project: &Path,
+) -> Result<Vec<String>, RextTuiError> {
+    let mut history = read_endpoint_history(dir)?;
+    Ok(history
+        .projects
+        .remove(project.to_string_lossy().as_ref())
+        .unwrap_or_default())
+}
+
+/// Reads endpoint_history.toml in the config directory `dir`
+fn read_endpoint_history(dir: &RextConfigDir) -> Result<EndpointHistory, RextTuiError> {
+    let history_path = dir.endpoint_history_path();
+    let contents = fs::read_to_string(&history_path).map_err(RextTuiError::ReadConfigFile)?;
+    toml::from_str(&contents).map_err(RextTuiError::ConfigError)
+}
+
+/// Saves the API endpoint history of the project in `project` to ~/.rext/endpoint_history.toml
+///
+/// Only the most recent [`MAX_ENDPOINT_HISTORY`] entries are kept, the other projects'
+/// histories are left as they are.
+///
+/// # Arguments
+///
+/// * `project` - The project directory
+/// * `history` - The endpoint names to save, oldest first
+///
+/// # Returns
+///
+/// - `Ok(())`: History successfully saved
+/// - `Err(RextTuiError)`: Serialization error or I/O error
+pub fn save_endpoint_history(project: &Path, history: &[String]) -> Result<(), RextTuiError> {
+    save_endpoint_history_in(&get_rext_config_dir()?, project, history)
+}
+
+/// Saves the API endpoint history of the project in `project` to endpoint_history.toml in the
+/// config directory `dir`, see [`save_endpoint_history`]
+pub fn save_endpoint_history_in(
+    dir: &RextConfigDir,
+    project: &Path,
+    history: &[String],
+) -> Result<(), RextTuiError> {
+    let mut saved = read_endpoint_history(dir).unwrap_or_default();
+    let start = history.len().saturating_sub(MAX_ENDPOINT_HISTORY);
+    saved.projects.insert(
+        project.to_string_lossy().into_owned(),
+        history[start..].to_vec(),
+    );
+    let contents = toml::to_string(&saved).map_err(RextTuiError::SerializeError)?;
+    fs::write(dir.endpoint_history_path(), contents).map_err(RextTuiError::WriteConfigFile)?;
+    Ok(())
+}
+
+/// Loads the last session snapshot from ~/.rext/session.toml
+///
+/// # Returns
+///
+/// - `Ok(AppSnapshot)`: The saved session
+/// - `Err(RextTuiError)`: File not found, parse error, or I/O error
+pub fn load_session_snapshot() -> Result<AppSnapshot, RextTuiError> {
+    load_session_snapshot_in(&get_rext_config_dir()?)
+}
+
+/// Loads the last session snapshot from session.toml in the config directory `dir`
+pub fn load_session_snapshot_in(dir: &RextConfigDir) -> Result<AppSnapshot, RextTuiError> {
+    let session_path = dir.session_path();
+    let contents = fs::read_to_string(&session_path).map_err(RextTuiError::ReadConfigFile)?;
+    let value: toml::Value = toml::from_str(&contents).map_err(RextTuiError::ConfigError)?;
+    AppSnapshot::try_from(value)
+}
+
+/// Saves a session snapshot to ~/.rext/session.toml
+///
+/// # Returns
+///
+/// - `Ok(())`: Snapshot successfully saved
+/// - `Err(RextTuiError)`: Serialization error or I/O error
+pub fn save_session_snapshot(snapshot: &AppSnapshot) -> Result<(), RextTuiError> {
+    save_session_snapshot_in(&get_rext_config_dir()?, snapshot)
+}
+
+/// Saves a session snapshot to session.toml in the config directory `dir`
+pub fn save_session_snapshot_in(
+    dir: &RextConfigDir,
+    snapshot: &AppSnapshot,
+) -> Result<(), RextTuiError> {
+    let contents = toml::to_string(snapshot).map_err(RextTuiError::SerializeError)?;
+    fs::write(dir.session_path(), contents).map_err(RextTuiError::WriteConfigFile)?;
+    Ok(())
+}
+
+/// Checks the config setup for problems, returns an empty list if everything is fine
+///
+/// Checks that ~/.rext/ is writable, preferences.toml parses, the current theme is in the
+/// config, rext_tui.toml has no unknown keys, the current language's localization loads,
+/// and no legacy preference files are left.
+pub fn health_check() -> Vec<HealthIssue> {
+    let dir = match get_rext_config_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            return vec![HealthIssue::new(
+                IssueSeverity::Error,
+                format!("The config directory ~/.rext/ isn't available: {e}"),
+                "Make sure the home directory exists and is writable",
+            )];
+        }
+    };
+    health_check_in(dir.path())
+}
+
+/// Runs the [`health_check`] checks against the config directory `dir`
+///
+/// The config, preferences and localization files are all read from `dir`, not ~/.rext/.
+fn health_check_in(dir: &Path) -> Vec<HealthIssue> {
+    let config_dir = RextConfigDir(dir.to_path_buf());
+    let mut issues = Vec::new();
+
+    let probe_path = dir.join(".health_check");
+    if let Err(e) = fs::write(&probe_path, "").and_then(|_| fs::remove_file(&probe_path)) {
+        issues.push(HealthIssue::new(
+            IssueSeverity::Error,
+            format!("The config directory {} isn't writable: {e}", dir.display()),
+            "Fix the directory permissions, preferences can't be saved until then",
+        ));
+    }
+
+    let preferences_path = dir.join("preferences.toml");
+    let preferences = match fs::read_to_string(&preferences_path) {
+        Ok(contents) => match toml::from_str::<UserPreferences>(&contents) {
+            Ok(preferences) => preferences,
+            Err(e) => {
+                issues.push(HealthIssue::new(
+                    IssueSeverity::Error,
+                    format!("preferences.toml can't be parsed: {e}"),
+                    "Fix or delete preferences.toml, it's recreated with the defaults",
+                ));
+                UserPreferences::default()
+            }
+        },
+        Err(_) => {
+            issues.push(HealthIssue::new(
+                IssueSeverity::Info,
+                "preferences.toml doesn't exist yet".to_string(),
+                "It's created when a theme or language is picked",
+            ));
+            UserPreferences::default()
+        }
+    };
+
+    match read_config_in(Some(&config_dir)) {
+        Ok(config) if !config.themes.contains_key(&preferences.theme) => {
+            issues.push(HealthIssue::new(
+                IssueSeverity::Warning,
+                format!(
+                    "The current theme '{}' isn't in the config",
+                    preferences.theme
+                ),
+                "Pick another theme in the settings, the default theme is used until then",
+            ));
+        }
+        Ok(_) => {}
+        Err(e) => {
+            issues.push(HealthIssue::new(
+                IssueSeverity::Error,
+                format!("The config can't be loaded: {e}"),
+                "Fix or delete ~/.rext/rext_tui.toml to use the embedded default config",
+            ));
+        }
+    }
+
+    let localization = read_localization_content_in(Some(&config_dir), &preferences.language)
+        .and_then(|content| {
+            toml::from_str::<toml::Table>(&content).map_err(RextTuiError::ConfigError)
+        });
+    if let Err(e) = localization {
+        issues.push(HealthIssue::new(
+            IssueSeverity::Error,
+            format!(
+                "The localization for '{}' can't be loaded: {e}",
+                preferences.language
+            ),
+            "Fix the localization file in ~/.rext/localization/ or pick another language",
+        ));
+    }
+
+    if let Ok(contents) = fs::read_to_string(config_dir.user_config_path()) {
+        for (key, closest) in unknown_config_keys(&contents) {
+            issues.push(HealthIssue::new(
+                IssueSeverity::Warning,
+                format!("Unknown key '{key}' in rext_tui.toml, it's ignored"),
+                &format!("Did you mean '{closest}'?"),
+            ));
+        }
+    }
+
+    for legacy_file in ["current_theme.toml", "current_localization.toml"] {
+        if dir.join(legacy_file).exists() {
+            issues.push(HealthIssue::new(
+                IssueSeverity::Info,
+                format!("The legacy preference file {legacy_file} is still present"),
+                "It was merged into preferences.toml and can be deleted",
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Loads the project's SeaORM config from ./.rext/seaorm.toml
+///
+/// # Returns
+///
+/// - `Ok(SeaOrmConfig)`: The saved SeaORM config
+/// - `Err(RextTuiError)`: File not found, parse error, or I/O error
+pub fn load_sea_orm_config() -> Result<SeaOrmConfig, RextTuiError> {
+    load_sea_orm_config_in(Path::new("."))
+}
+
+/// Saves the project's SeaORM config to ./.rext/seaorm.toml
+///
+/// Creates the project's .rext directory if it doesn't exist.
+///
+/// # Returns
+///
+/// - `Ok(())`: Config successfully saved
+/// - `Err(RextTuiError)`: Serialization error or I/O error
+pub fn save_sea_orm_config(config: &SeaOrmConfig) -> Result<(), RextTuiError> {
+    save_sea_orm_config_in(Path::new("."), config)
+}
+
+/// Loads the SeaORM config of the project in `project_dir` from its .rext/seaorm.toml
+pub fn load_sea_orm_config_in(project_dir: &Path) -> Result<SeaOrmConfig, RextTuiError> {
+    let contents = fs::read_to_string(project_dir.join(SEA_ORM_CONFIG_PATH))
+        .map_err(RextTuiError::ReadConfigFile)?;
+    toml::from_str(&contents).map_err(RextTuiError::ConfigError)
+}
+
+/// Saves the SeaORM config of the project in `project_dir` to its .rext/seaorm.toml
+pub fn save_sea_orm_config_in(
+    project_dir: &Path,
+    config: &SeaOrmConfig,
+) -> Result<(), RextTuiError> {
+    let contents = toml::to_string(config).map_err(RextTuiError::SerializeError)?;
+    let path = project_dir.join(SEA_ORM_CONFIG_PATH);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(RextTuiError::WriteConfigFile)?;
+    }
+    fs::write(path, contents).map_err(RextTuiError::WriteConfigFile)?;
     Ok(())
 }
 
@@ -349,6 +1362,8 @@ pub fn get_available_languages_with_display() -> Result<Vec<(String, String)>, R
 /// Loads localization content for a specific language
 ///
 /// Checks for user localization files first, falls back to embedded defaults.
+/// The content is cached for the session, call [`invalidate_localization_cache`]
+/// after a localization file changes.
 ///
 /// # Arguments
 ///
@@ -359,10 +1374,45 @@ pub fn get_available_languages_with_display() -> Result<Vec<(String, String)>, R
 /// - `Ok(String)`: The localization file content
 /// - `Err(RextTuiError)`: Language not supported
 pub fn load_localization_content(language_code: &str) -> Result<String, RextTuiError> {
+    if let Some(content) = LOCALIZATION_CACHE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(language_code)
+    {
+        return Ok(content.clone());
+    }
+
+    let content = read_localization_content(language_code)?;
+    LOCALIZATION_CACHE
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(language_code.to_string(), content.clone());
+    Ok(content)
+}
+
+/// Clears the cached localization content for `language_code`, so it's read again
+pub fn invalidate_localization_cache(language_code: &str) {
+    LOCALIZATION_CACHE
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(language_code);
+}
+
+/// Reads the localization content from the user localization file or the embedded defaults
+fn read_localization_content(language_code: &str) -> Result<String, RextTuiError> {
+    read_localization_content_in(get_rext_config_dir().ok().as_ref(), language_code)
+}
+
+/// Reads the localization content from the user localization file in `config_dir`,
+/// or the embedded defaults
+fn read_localization_content_in(
+    config_dir: Option<&RextConfigDir>,
+    language_code: &str,
+) -> Result<String, RextTuiError> {
     // Try user localization file first
-    if let Ok(rext_dir) = get_rext_config_dir() {
+    if let Some(rext_dir) = config_dir {
         let user_localization_path = rext_dir
-            .join("localization")
+            .localization_dir()
             .join(format!("{}.toml", language_code));
         if user_localization_path.exists() {
             if let Ok(contents) = fs::read_to_string(&user_localization_path) {
@@ -378,8 +1428,536 @@ pub fn load_localization_content(language_code: &str) -> Result<String, RextTuiE
     let content = match language_code {
         "en" => EN_LOCALIZATION,
         "fr" => FR_LOCALIZATION,
-        _ => EN_LOCALIZATION, // Default to English for unsupported languages
+        _ => return Err(RextTuiError::LanguageNotFound(language_code.to_string())),
     };
 
     Ok(content.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_from_toml_value_names_the_failing_field() {
+        let value: toml::Value = toml::from_str(
+            r##"
+            primary = "#FF6B35"
+            text = { r = 204, g = 205, b = 204 }
+            background = "#1a1a1a"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(
+            Colors::from_toml_value(&value),
+            Ok(Colors {
+                primary: Rgb {
+                    r: 255,
+                    g: 107,
+                    b: 53
+                },
+                text: Rgb {
+                    r: 204,
+                    g: 205,
+                    b: 204
+                },
+                background: Rgb {
+                    r: 26,
+                    g: 26,
+                    b: 26
+                },
+            })
+        );
+
+        let error = |toml: &str| Colors::from_toml_value(&toml::from_str(toml).unwrap());
+        assert_eq!(
+            error("primary = \"#ff6b3\"\ntext = \"#000000\"\nbackground = \"#000000\""),
+            Err("primary: expected \"#rrggbb\", got \"#ff6b3\"".to_string())
+        );
+        assert_eq!(
+            error(
+                "primary = \"#ff6b35\"\ntext = { r = 256, g = 0, b = 0 }\nbackground = \"#000000\""
+            ),
+            Err("text: component r must be between 0 and 255".to_string())
+        );
+        assert_eq!(
+            error("primary = \"#ff6b35\"\ntext = \"#000000\""),
+            Err("background: missing".to_string())
+        );
+    }
+
+    #[cfg(feature = "yaml-config")]
+    #[test]
+    fn config_round_trips_through_yaml() {
+        let config: Config = toml::from_str(DEFAULT_CONFIG).unwrap();
+        let yaml = export_config_as_yaml(&config).unwrap();
+        let parsed: Config = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(parsed.themes, config.themes);
+        assert_eq!(
+            parsed
+                .localization
+                .keys()
+                .collect::<std::collections::BTreeSet<_>>(),
+            config.localization.keys().collect()
+        );
+    }
+
+    #[test]
+    fn rgb_add_saturates() {
+        let sum = Rgb {
+            r: 200,
+            g: 10,
+            b: 255,
+        } + Rgb {
+            r: 100,
+            g: 20,
+            b: 1,
+        };
+        assert_eq!(
+            sum,
+            Rgb {
+                r: 255,
+                g: 30,
+                b: 255
+            }
+        );
+    }
+
+    #[test]
+    fn rgb_blend() {
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        let white = Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+
+        assert_eq!(Rgb::blend(black.clone(), white.clone(), 0.0), black);
+        assert_eq!(Rgb::blend(black.clone(), white.clone(), 1.0), white);
+        assert_eq!(
+            Rgb::blend(black.clone(), white.clone(), 0.5),
+            Rgb {
+                r: 128,
+                g: 128,
+                b: 128
+            }
+        );
+        // Out of range factors are clamped
+        assert_eq!(Rgb::blend(black.clone(), white.clone(), 2.0), white);
+    }
+
+    #[test]
+    fn rgb_lighten_and_darken_saturate() {
+        let color = Rgb {
+            r: 250,
+            g: 100,
+            b: 5,
+        };
+        assert_eq!(
+            color.lighten(10),
+            Rgb {
+                r: 255,
+                g: 110,
+                b: 15
+            }
+        );
+        assert_eq!(
+            color.darken(10),
+            Rgb {
+                r: 240,
+                g: 90,
+                b: 0
+            }
+        );
+    }
+
+    #[test]
+    fn rgb_hashes_consistently_with_eq() {
+        use std::hash::BuildHasher;
+
+        let orange = Rgb {
+            r: 255,
+            g: 107,
+            b: 53,
+        };
+        let hasher = std::collections::hash_map::RandomState::new();
+        assert_eq!(
+            hasher.hash_one(orange.clone()),
+            hasher.hash_one(Rgb {
+                r: 255,
+                g: 107,
+                b: 53
+            })
+        );
+
+        let mut names = HashMap::new();
+        names.insert(orange.clone(), "orange".to_string());
+        assert_eq!(names.get(&orange).map(String::as_str), Some("orange"));
+    }
+
+    #[test]
+    fn colors_compare_by_value() {
+        let rgb = |r, g, b| Rgb { r, g, b };
+        let colors = Colors {
+            primary: rgb(255, 107, 53),
+            text: rgb(204, 205, 204),
+            background: rgb(26, 26, 26),
+        };
+
+        assert_eq!(colors, colors.clone());
+        assert_ne!(
+            colors,
+            Colors {
+                primary: rgb(0, 0, 0),
+                ..colors.clone()
+            }
+        );
+    }
+
+    /// A fresh config directory in the system temp directory, unique to the test `name`
+    fn temp_config_dir(name: &str) -> RextConfigDir {
+        let path = std::env::temp_dir().join(format!("rext_tui_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        RextConfigDir::new(path).unwrap()
+    }
+
+    #[test]
+    fn endpoint_history_is_kept_per_project() {
+        let config_dir = temp_config_dir("endpoint_history");
+        let (first, second) = (Path::new("/tmp/first"), Path::new("/tmp/second"));
+        save_endpoint_history_in(&config_dir, first, &["users".to_string()]).unwrap();
+        save_endpoint_history_in(&config_dir, second, &["posts".to_string()]).unwrap();
+
+        assert_eq!(
+            load_endpoint_history_in(&config_dir, first).unwrap(),
+            ["users"]
+        );
+        assert_eq!(
+            load_endpoint_history_in(&config_dir, second).unwrap(),
+            ["posts"]
+        );
+        assert!(
+            load_endpoint_history_in(&config_dir, Path::new("/tmp/third"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_preference_files() {
+        let config_dir = temp_config_dir("migration");
+        let dir = config_dir.path();
+        fs::write(dir.join("current_theme.toml"), "current_theme = \"nord\"\n").unwrap();
+        fs::write(
+            dir.join("current_localization.toml"),
+            "current_localization = \"fr\"\n",
+        )
+        .unwrap();
+
+        let preferences = load_preferences_in(&config_dir).unwrap();
+        assert_eq!(
+            preferences,
+            UserPreferences {
+                theme: "nord".to_string(),
+                language: "fr".to_string(),
+                recently_used_themes: Vec::new(),
+                language_sticky_search: true,
+                telemetry_enabled: false,
+                telemetry_prompted: false,
+            }
+        );
+
+        // The merged file is written and used from then on
+        fs::remove_file(dir.join("current_theme.toml")).unwrap();
+        fs::remove_file(dir.join("current_localization.toml")).unwrap();
+        assert_eq!(load_preferences_in(&config_dir).unwrap(), preferences);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// Modification time of preferences.toml in the config directory `dir`
+    fn preferences_modified(dir: &RextConfigDir) -> std::time::SystemTime {
+        fs::metadata(dir.preferences_path())
+            .unwrap()
+            .modified()
+            .unwrap()
+    }
+
+    #[test]
+    fn theme_colors_come_from_the_given_config() {
+        let rgb = |r, g, b| Rgb { r, g, b };
+        let colors = Colors {
+            primary: rgb(1, 2, 3),
+            text: rgb(4, 5, 6),
+            background: rgb(7, 8, 9),
+        };
+        let config = Config {
+            meta: None,
+            themes: HashMap::from([("only_in_memory".to_string(), colors.clone())]),
+            localization: HashMap::new(),
+            user_meta: None,
+        };
+
+        assert_eq!(
+            theme_colors_from("only_in_memory", &config).unwrap(),
+            colors
+        );
+        assert!(matches!(
+            theme_colors_from("rust", &config),
+            Err(RextTuiError::ThemeNotFound(name)) if name == "rust"
+        ));
+    }
+
+    #[test]
+    fn loads_theme_from_colors_file() {
+        let path = std::env::temp_dir().join(format!("rext_tui_theme_{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "[colors]\n\
+             text = { r = 1, g = 2, b = 3 }\n\
+             primary = { r = 4, g = 5, b = 6 }\n\
+             background = { r = 7, g = 8, b = 9 }\n",
+        )
+        .unwrap();
+
+        let colors = load_theme_from_file(&path).unwrap();
+        assert_eq!(colors.text, Rgb { r: 1, g: 2, b: 3 });
+        assert_eq!(colors.primary, Rgb { r: 4, g: 5, b: 6 });
+        assert_eq!(colors.background, Rgb { r: 7, g: 8, b: 9 });
+
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            load_theme_from_file(&path),
+            Err(RextTuiError::ReadConfigFile(_))
+        ));
+    }
+
+    #[test]
+    fn reports_unknown_top_level_config_keys() {
+        assert!(unknown_config_keys(DEFAULT_CONFIG).is_empty());
+
+        let contents = "[thems.rust]\nprimary = 1\n\n[localisation.en]\ndisplay = \"English\"\n\n[user_meta]\n";
+        let mut unknown = unknown_config_keys(contents);
+        unknown.sort();
+        assert_eq!(
+            unknown,
+            [
+                ("localisation".to_string(), "localization"),
+                ("thems".to_string(), "themes"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_configs_newer_than_supported() {
+        let default: Config = toml::from_str(DEFAULT_CONFIG).unwrap();
+        assert_eq!(
+            default.meta,
+            Some(ConfigMeta {
+                version: SUPPORTED_CONFIG_VERSION
+            })
+        );
+        assert!(check_config_version(default).is_ok());
+
+        let unversioned: Config = toml::from_str("[themes]\n[localization]\n").unwrap();
+        assert!(check_config_version(unversioned).is_ok());
+
+        let newer: Config =
+            toml::from_str("[meta]\nversion = 2\n[themes]\n[localization]\n").unwrap();
+        let Err(error) = check_config_version(newer) else {
+            panic!("a newer config version should be rejected");
+        };
+        assert!(matches!(
+            error,
+            RextTuiError::UnsupportedConfigVersion {
+                found: 2,
+                supported: 1
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Config file was created by a newer version of rext-tui (v2) and cannot be read by this version (v1)"
+        );
+    }
+
+    #[test]
+    fn current_theme_is_listed_first() {
+        let sorted = get_available_themes(None).unwrap();
+        let current = &sorted[1];
+        let themes = get_available_themes(Some(current)).unwrap();
+
+        assert_eq!(&themes[0], current);
+        assert_eq!(themes[1..], [&sorted[2..], &sorted[..1]].concat()[..]);
+        assert_eq!(get_available_themes(Some("missing")).unwrap(), sorted);
+    }
+
+    #[test]
+    fn saving_unchanged_theme_skips_write() {
+        let dir = temp_config_dir("unchanged_theme");
+        save_current_theme_in(&dir, "nord").unwrap();
+        let before = preferences_modified(&dir);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        save_current_theme_in(&dir, "nord").unwrap();
+        assert_eq!(preferences_modified(&dir), before);
+        assert_eq!(load_preferences_in(&dir).unwrap().theme, "nord");
+
+        fs::remove_dir_all(dir.path()).unwrap();
+    }
+
+    #[test]
+    fn saving_unchanged_language_skips_write() {
+        let dir = temp_config_dir("unchanged_language");
+        save_current_language_in(&dir, "fr").unwrap();
+        let before = preferences_modified(&dir);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        save_current_language_in(&dir, "fr").unwrap();
+        assert_eq!(preferences_modified(&dir), before);
+        assert_eq!(load_preferences_in(&dir).unwrap().language, "fr");
+
+        fs::remove_dir_all(dir.path()).unwrap();
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn save_current_theme_async_writes_preferences() {
+        let dir = temp_config_dir("async_theme");
+        save_current_language_in(&dir, "fr").unwrap();
+        save_current_theme_in_async(&dir, "nord").await.unwrap();
+        save_current_language_in_async(&dir, "fr").await.unwrap();
+
+        let preferences = load_preferences_in(&dir).unwrap();
+        assert_eq!(preferences.theme, "nord");
+        assert_eq!(preferences.language, "fr");
+
+        fs::remove_dir_all(dir.path()).unwrap();
+    }
+
+    #[test]
+    fn health_check_reports_broken_and_legacy_files() {
+        let dir = std::env::temp_dir().join(format!("rext_tui_health_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        assert!(
+            health_check_in(&dir)
+                .iter()
+                .any(|issue| issue.severity == IssueSeverity::Info)
+        );
+
+        fs::write(dir.join("preferences.toml"), "theme = ").unwrap();
+        fs::write(dir.join("current_theme.toml"), "current_theme = \"nord\"\n").unwrap();
+        let issues = health_check_in(&dir);
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.severity == IssueSeverity::Error
+                    && issue.description.contains("preferences.toml"))
+        );
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.severity == IssueSeverity::Info
+                    && issue.description.contains("current_theme.toml"))
+        );
+
+        fs::write(
+            dir.join("preferences.toml"),
+            "theme = \"missing\"\nlanguage = \"en\"\n",
+        )
+        .unwrap();
+        fs::remove_file(dir.join("current_theme.toml")).unwrap();
+        let issues = health_check_in(&dir);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+
+        // Themes and localizations are looked up in the checked directory's own files
+        let mut custom_config: toml::Table = toml::from_str(DEFAULT_CONFIG).unwrap();
+        let rust_theme = custom_config["themes"]["rust"].clone();
+        custom_config["themes"]
+            .as_table_mut()
+            .unwrap()
+            .insert("missing".to_string(), rust_theme);
+        fs::write(
+            dir.join("rext_tui.toml"),
+            toml::to_string(&custom_config).unwrap(),
+        )
+        .unwrap();
+        assert!(health_check_in(&dir).is_empty());
+
+        fs::create_dir_all(dir.join("localization")).unwrap();
+        fs::write(
+            dir.join("preferences.toml"),
+            "theme = \"missing\"\nlanguage = \"de\"\n",
+        )
+        .unwrap();
+        assert_eq!(health_check_in(&dir).len(), 1);
+        fs::write(dir.join("localization").join("de.toml"), "[ui]\n").unwrap();
+        assert!(health_check_in(&dir).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn health_check_reports_unknown_config_keys() {
+        let dir = temp_config_dir("health_unknown_keys");
+        fs::write(
+            dir.preferences_path(),
+            "theme = \"rust\"\nlanguage = \"en\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.user_config_path(),
+            format!("{DEFAULT_CONFIG}\n[thems.nord]\nprimary = \"#000000\"\n"),
+        )
+        .unwrap();
+
+        let issues = health_check_in(dir.path());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert!(issues[0].description.contains("'thems'"));
+        assert_eq!(issues[0].suggestion, "Did you mean 'themes'?");
+
+        fs::remove_dir_all(dir.path()).unwrap();
+    }
+
+    #[test]
+    fn localization_content_is_cached_until_invalidated() {
+        // A language only present in the cache is served from it until invalidated
+        LOCALIZATION_CACHE
+            .write()
+            .unwrap()
+            .insert("x-cached".to_string(), "[ui]".to_string());
+        assert_eq!(load_localization_content("x-cached").unwrap(), "[ui]");
+
+        invalidate_localization_cache("x-cached");
+        assert!(matches!(
+            load_localization_content("x-cached"),
+            Err(RextTuiError::LanguageNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn app_snapshot_from_toml_value() {
+        let value: toml::Value =
+            toml::from_str("theme = \"nord\"\nlanguage = \"fr\"\nlast_dialog = \"settings\"")
+                .unwrap();
+        let snapshot = AppSnapshot::try_from(value).unwrap();
+        assert_eq!(
+            snapshot,
+            AppSnapshot {
+                theme: "nord".to_string(),
+                language: "fr".to_string(),
+                last_dialog: "settings".to_string(),
+            }
+        );
+
+        let missing: toml::Value = toml::from_str("theme = \"nord\"").unwrap();
+        assert!(AppSnapshot::try_from(missing).is_err());
+    }
+
+    #[test]
+    fn load_config_is_cached() {
+        let first = load_config().unwrap();
+        let second = load_config().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+}
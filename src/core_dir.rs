@@ -0,0 +1,64 @@
+//! # rext-core Operations in Other Directories
+//!
+//! rext-core only works in the current directory. [`DefaultRextCore`](crate::DefaultRextCore)
+//! runs operations for another directory with [`in_dir`], which switches the current
+//! directory of the process for the duration of the operation and switches back afterwards.
+//!
+//! The current directory is shared by the whole process, so the switches are serialized with
+//! a mutex. Code on other threads that resolves relative paths while an operation runs sees
+//! the operation's directory.
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// Held while the current directory is switched, so only one operation switches it at a time
+static CURRENT_DIR_LOCK: Mutex<()> = Mutex::new(());
+
+/// Switches back to `0` when dropped, also when the operation panics
+struct RestoreDir(PathBuf);
+
+impl Drop for RestoreDir {
+    fn drop(&mut self) {
+        if let Err(e) = std::env::set_current_dir(&self.0) {
+            log::warn!("failed to switch back to {}: {}", self.0.display(), e);
+        }
+    }
+}
+
+/// Runs `operation` with `dir` as the current directory, then switches back
+///
+/// Fails without running `operation` if the current directory can't be read or switched.
+pub fn in_dir<T>(dir: &Path, operation: impl FnOnce() -> T) -> Result<T, Box<dyn Error>> {
+    let _lock = CURRENT_DIR_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let previous = std::env::current_dir()?;
+    if previous.canonicalize()? == dir.canonicalize()? {
+        return Ok(operation());
+    }
+
+    std::env::set_current_dir(dir)?;
+    let _restore = RestoreDir(previous);
+    Ok(operation())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_run_in_the_dir_and_switch_back() {
+        let dir = std::env::temp_dir().join(format!("rext_tui_core_dir_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let before = std::env::current_dir().unwrap();
+
+        let during = in_dir(&dir, || std::env::current_dir().unwrap()).unwrap();
+        assert_eq!(during.canonicalize().unwrap(), dir.canonicalize().unwrap());
+        assert_eq!(std::env::current_dir().unwrap(), before);
+
+        // Missing directories fail before running the operation
+        assert!(in_dir(&dir.join("missing"), || unreachable!()).is_err());
+        assert_eq!(std::env::current_dir().unwrap(), before);
+    }
+}
@@ -6,11 +6,117 @@ pub enum RextTuiError {
     #[error("Failed to load config: {0}")]
     ConfigError(#[from] toml::de::Error),
     #[error("Failed to read config file: {0}")]
-    ReadConfigFile(std::io::Error),
+    ReadConfigFile(#[source] std::io::Error),
     #[error("Failed to write config file: {0}")]
-    WriteConfigFile(std::io::Error),
+    WriteConfigFile(#[source] std::io::Error),
     #[error("Failed to serialize config: {0}")]
     SerializeError(#[from] toml::ser::Error),
     #[error("Theme '{0}' not found")]
     ThemeNotFound(String),
+    #[error("Language '{0}' not found")]
+    LanguageNotFound(String),
+    #[error("Environment variable error: {0}")]
+    EnvVarError(#[from] std::env::VarError),
+    #[error("Another rext-tui instance is running")]
+    AlreadyRunning,
+    #[error("Failed to lock the instance lock file: {0}")]
+    InstanceLock(#[source] std::io::Error),
+    #[error(
+        "Config file was created by a newer version of rext-tui (v{found}) and cannot be read by this version (v{supported})"
+    )]
+    UnsupportedConfigVersion { found: u32, supported: u32 },
+    #[cfg(feature = "yaml-config")]
+    #[error("Failed to read YAML config: {0}")]
+    YamlError(serde_yaml::Error),
+    #[cfg(feature = "gettext")]
+    #[error("Failed to parse PO file: {0}")]
+    ParsePoFile(polib::po_file::POParseError),
+}
+
+impl RextTuiError {
+    /// A short message safe to show in the UI
+    ///
+    /// Unlike `Display`, it never includes paths or the underlying error,
+    /// use `Display` for logs and debug output.
+    pub fn user_message(&self) -> &str {
+        match self {
+            RextTuiError::ReadEvent(_) => "Could not read terminal input",
+            RextTuiError::ConfigError(_) => "The configuration file is invalid",
+            RextTuiError::ReadConfigFile(_) => "Could not read configuration file",
+            RextTuiError::WriteConfigFile(_) => "Could not save configuration file",
+            RextTuiError::SerializeError(_) => "Could not save configuration",
+            RextTuiError::ThemeNotFound(_) => "The selected theme is not available",
+            RextTuiError::LanguageNotFound(_) => "The selected language is not available",
+            RextTuiError::EnvVarError(_) => "An environment variable is missing or invalid",
+            RextTuiError::AlreadyRunning => "Another rext-tui instance is running",
+            RextTuiError::InstanceLock(_) => "Could not check for other rext-tui instances",
+            RextTuiError::UnsupportedConfigVersion { .. } => {
+                "The configuration file is from a newer version of rext-tui"
+            }
+            #[cfg(feature = "yaml-config")]
+            RextTuiError::YamlError(_) => "The YAML configuration file is invalid",
+            #[cfg(feature = "gettext")]
+            RextTuiError::ParsePoFile(_) => "A translation file is invalid",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::VarError;
+    use std::error::Error;
+    use std::ffi::OsString;
+
+    #[test]
+    fn config_file_errors_expose_io_source() {
+        let error = RextTuiError::ReadConfigFile(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "preferences.toml",
+        ));
+        let source = error.source().unwrap();
+        assert_eq!(source.to_string(), "preferences.toml");
+    }
+
+    #[test]
+    fn non_unicode_env_var_converts_to_error() {
+        fn read() -> Result<String, RextTuiError> {
+            Err(VarError::NotUnicode(OsString::from("theme")))?
+        }
+
+        let error = read().unwrap_err();
+        assert!(matches!(
+            error,
+            RextTuiError::EnvVarError(VarError::NotUnicode(_))
+        ));
+        assert!(error.to_string().starts_with("Environment variable error:"));
+    }
+
+    #[test]
+    fn user_messages_hide_paths() {
+        let io = || std::io::Error::new(std::io::ErrorKind::NotFound, "/home/user/.rext/x.toml");
+        let path = "/home/user/.rext/themes/custom.toml".to_string();
+        let errors = [
+            RextTuiError::ReadEvent(io()),
+            RextTuiError::ConfigError(toml::from_str::<toml::Table>("= /x").unwrap_err()),
+            RextTuiError::ReadConfigFile(io()),
+            RextTuiError::WriteConfigFile(io()),
+            RextTuiError::SerializeError(<toml::ser::Error as serde::ser::Error>::custom("/x")),
+            RextTuiError::ThemeNotFound(path.clone()),
+            RextTuiError::LanguageNotFound(path),
+            RextTuiError::EnvVarError(VarError::NotPresent),
+            RextTuiError::AlreadyRunning,
+            RextTuiError::InstanceLock(io()),
+            RextTuiError::UnsupportedConfigVersion {
+                found: 2,
+                supported: 1,
+            },
+        ];
+
+        for error in errors {
+            let message = error.user_message();
+            assert!(!message.is_empty());
+            assert!(!message.contains('/') && !message.contains('\\'));
+        }
+    }
 }
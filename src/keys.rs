@@ -0,0 +1,142 @@
+//! # Key Strings
+//!
+//! Parsing of the key strings used for key bindings in the `[keys]` section of the
+//! localization files, public so other tools can validate their own key strings.
+//!
+//! Supported formats:
+//! - Single characters: "q", "a", "1"
+//! - Special keys: "Esc", "Enter", "Backspace", "Tab", "Delete", "Space"
+//! - Arrow keys: "Up", "Down", "Left", "Right"
+//! - Navigation: "Home", "End", "PageUp", "PageDown"
+//! - Function keys: "F1", "F2", ..., "F12"
+//! - Modifiers: "Ctrl+C", "Shift+Tab", "Alt+Enter"
+//! - Case insensitive: "up", "UP", "Up" all work
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Parses a key string into KeyModifiers and KeyCode, `None` if it isn't a valid key
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use rext_tui::keys::parse_key_string;
+///
+/// assert_eq!(
+///     parse_key_string("Ctrl+C"),
+///     Some((KeyModifiers::CONTROL, KeyCode::Char('C')))
+/// );
+/// assert_eq!(parse_key_string("pgup"), Some((KeyModifiers::NONE, KeyCode::PageUp)));
+/// assert_eq!(parse_key_string("Hyper+X"), None);
+/// ```
+pub fn parse_key_string(key_str: &str) -> Option<(KeyModifiers, KeyCode)> {
+    parse_key_string_strict(key_str).ok()
+}
+
+/// Parses a key string into KeyModifiers and KeyCode, with a description of what's wrong if it isn't valid
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use rext_tui::keys::parse_key_string_strict;
+///
+/// assert_eq!(
+///     parse_key_string_strict("Shift+Tab"),
+///     Ok((KeyModifiers::SHIFT, KeyCode::Tab))
+/// );
+/// assert_eq!(
+///     parse_key_string_strict("Hyper+X"),
+///     Err("Unknown modifier 'hyper' in key combination 'Hyper+X'".to_string())
+/// );
+/// assert_eq!(
+///     parse_key_string_strict("Ctrl+Alt+X"),
+///     Err("Invalid key combination 'Ctrl+Alt+X'".to_string())
+/// );
+/// ```
+pub fn parse_key_string_strict(key_str: &str) -> Result<(KeyModifiers, KeyCode), String> {
+    let key_str = key_str.trim();
+
+    // Handle modifier combinations
+    if key_str.contains('+') {
+        return parse_modified_key(key_str);
+    }
+
+    // Handle single keys (case-insensitive)
+    let normalized = key_str.to_lowercase();
+    let key_code = match normalized.as_str() {
+        // Special keys
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "backspace" | "back" => KeyCode::Backspace,
+        "tab" => KeyCode::Tab,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "space" | "spacebar" => KeyCode::Char(' '),
+        "null" => KeyCode::Null,
+
+        // Arrow keys
+        "up" | "uparrow" => KeyCode::Up,
+        "down" | "downarrow" => KeyCode::Down,
+        "left" | "leftarrow" => KeyCode::Left,
+        "right" | "rightarrow" => KeyCode::Right,
+
+        // Navigation keys
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+
+        // Function keys
+        "f1" => KeyCode::F(1),
+        "f2" => KeyCode::F(2),
+        "f3" => KeyCode::F(3),
+        "f4" => KeyCode::F(4),
+        "f5" => KeyCode::F(5),
+        "f6" => KeyCode::F(6),
+        "f7" => KeyCode::F(7),
+        "f8" => KeyCode::F(8),
+        "f9" => KeyCode::F(9),
+        "f10" => KeyCode::F(10),
+        "f11" => KeyCode::F(11),
+        "f12" => KeyCode::F(12),
+
+        // Single character keys, in their original case
+        single_char if single_char.len() == 1 => match key_str.chars().next() {
+            Some(ch) => KeyCode::Char(ch),
+            None => return Err(format!("Unknown key string '{}'", key_str)),
+        },
+
+        // Unknown key
+        _ => return Err(format!("Unknown key string '{}'", key_str)),
+    };
+    Ok((KeyModifiers::NONE, key_code))
+}
+
+/// Parses modified key combinations like "Ctrl+C", "Shift+Tab", "Alt+Enter"
+fn parse_modified_key(key_str: &str) -> Result<(KeyModifiers, KeyCode), String> {
+    let parts: Vec<&str> = key_str.split('+').collect();
+    if parts.len() != 2 {
+        return Err(format!("Invalid key combination '{}'", key_str));
+    }
+
+    let modifier_str = parts[0].trim().to_lowercase();
+    let key_part = parts[1].trim();
+
+    let modifiers = match modifier_str.as_str() {
+        "ctrl" | "control" => KeyModifiers::CONTROL,
+        "shift" => KeyModifiers::SHIFT,
+        "alt" => KeyModifiers::ALT,
+        _ => {
+            return Err(format!(
+                "Unknown modifier '{}' in key combination '{}'",
+                modifier_str, key_str
+            ));
+        }
+    };
+
+    // Parse the key part (recursively, but without modifiers)
+    match parse_key_string_strict(key_part) {
+        Ok((_, key_code)) => Ok((modifiers, key_code)),
+        Err(_) => Err(format!(
+            "Invalid key '{}' in combination '{}'",
+            key_part, key_str
+        )),
+    }
+}
@@ -16,30 +16,97 @@
 //! ## Localization
 //!
 //! Full localization support with the [`localization`] module for text and key bindings.
+//! Key binding strings (e.g. `"Ctrl+C"`) are parsed by the [`keys`] module.
+//!
+//! ## rext-core
+//!
+//! rext-core operations for a working directory other than the current one switch the
+//! current directory while they run, see the [`core_dir`] module.
 //!
 //! ## TODO
 //!
 //! - The render and app loop should not fail due to missing or failed config files and loads.
 //! - Update the app so we have sensible defaults when any config files are missing or fail to load.
 
+mod block_text;
 pub mod config;
+pub mod core_dir;
 pub mod error;
+pub mod keys;
 pub mod localization;
+pub mod operation_log;
+pub mod telemetry;
+
+/// Re-exports of the commonly used types and functions, for `use rext_tui::prelude::*`
+pub mod prelude {
+    pub use crate::config::{
+        AppSnapshot, Colors, Config, ConfigMeta, DEFAULT_SEA_ORM_OUTPUT_DIR, EndpointHistory,
+        HealthIssue, IssueSeverity, LocalizationConfig, MAX_ENDPOINT_HISTORY, MAX_RECENT_THEMES,
+        RextConfigDir, Rgb, SEA_ORM_CONFIG_PATH, SUPPORTED_CONFIG_VERSION, SeaOrmConfig,
+        UserPreferences, get_available_languages, get_available_languages_with_display,
+        get_available_themes, get_rext_config_dir, health_check, invalidate_config_cache,
+        invalidate_localization_cache, load_config, load_current_language, load_current_theme,
+        load_endpoint_history, load_endpoint_history_in, load_language_sticky_search,
+        load_localization_content, load_preferences, load_preferences_in,
+        load_recently_used_themes, load_sea_orm_config, load_sea_orm_config_in,
+        load_session_snapshot, load_session_snapshot_in, load_theme_colors, load_theme_from_file,
+        save_current_language, save_current_language_in, save_current_theme, save_current_theme_in,
+        save_endpoint_history, save_endpoint_history_in, save_language_sticky_search,
+        save_language_sticky_search_in, save_preferences, save_preferences_in,
+        save_recently_used_themes, save_recently_used_themes_in, save_sea_orm_config,
+        save_sea_orm_config_in, save_session_snapshot, save_session_snapshot_in,
+        save_telemetry_enabled, save_telemetry_enabled_in, theme_colors_from,
+    };
+    pub use crate::error::RextTuiError;
+    pub use crate::localization::{
+        Localization, LocalizedTexts, MissingKeyPolicy, PartialMatchResult,
+    };
+    pub use crate::operation_log::{MAX_OPERATION_RECORDS, OperationRecord};
+    pub use crate::telemetry::TelemetryRecorder;
+    pub use crate::{
+        App, AppBuilder, AppExitReason, DialogType, MAX_NOTIFICATIONS, MAX_UNDO_STEPS,
+        Notification, RextCoreTrait, SettingsOption, TerminalModes, UndoAction, WorkspaceInfo,
+        filter_languages,
+    };
+}
 
 use crate::config::{
-    get_available_languages_with_display, get_available_themes, load_current_language,
-    load_current_theme, load_theme_colors, save_current_language, save_current_theme,
+    AppSnapshot, Config, DEFAULT_SEA_ORM_OUTPUT_DIR, MAX_ENDPOINT_HISTORY, MAX_RECENT_THEMES,
+    RextConfigDir, SeaOrmConfig, UserPreferences, get_available_languages_with_display,
+    get_available_themes, get_rext_config_dir, invalidate_config_cache,
+    invalidate_localization_cache, load_config, load_endpoint_history_in, load_preferences_in,
+    load_sea_orm_config_in, load_session_snapshot_in, load_theme_colors, load_theme_from_file,
+    save_endpoint_history_in, save_preferences_in, save_sea_orm_config_in,
+    save_session_snapshot_in, theme_colors_from,
 };
 use crate::error::RextTuiError;
-use crate::localization::Localization;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crate::localization::{Localization, MissingKeyPolicy, PartialMatchResult};
+use crate::operation_log::{MAX_OPERATION_RECORDS, OperationRecord};
+use crate::telemetry::TelemetryRecorder;
+use crossterm::event::{
+    self, DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange,
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, KeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
+use crossterm::terminal::LeaveAlternateScreen;
+use fs2::FileExt;
 use ratatui::text::Line;
 use ratatui::{
-    DefaultTerminal, Frame,
+    Frame, Terminal,
+    backend::{Backend, CrosstermBackend, TestBackend},
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style, Stylize},
-    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
 };
+#[cfg(any(test, feature = "test-support"))]
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+#[cfg(any(test, feature = "test-support"))]
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use unicode_normalization::UnicodeNormalization;
 
 /// Dialog types for the application
 ///
@@ -47,6 +114,13 @@ use ratatui::{
 /// - `ApiEndpoint`: API endpoint creation dialog
 /// - `Settings`: Settings dialog
 /// - `Language`: Language selection dialog
+/// - `NewApp`: New app dialog, shown when no Rext app is found
+/// - `RecentThemes`: Recently used themes dialog
+/// - `SeaOrmConfig`: SeaORM database and output directory dialog, shown before entity generation
+/// - `TelemetryConsent`: First-run dialog asking to opt in to local telemetry
+/// - `ConfirmDestroy`: Asks to confirm destroying the Rext app, opened from the settings dialog or
+///   by undoing the app scaffolding if `undo`
+/// - `Error`: Error dialog showing `message` with an OK button
 #[derive(Debug, Clone, PartialEq)]
 pub enum DialogType {
     None,
@@ -54,19 +128,92 @@ pub enum DialogType {
     Settings,
     Language,
     NewApp,
+    RecentThemes,
+    SeaOrmConfig,
+    TelemetryConsent,
+    ConfirmDestroy { undo: bool },
+    Error { message: String },
+}
+
+/// Every [`DialogType`] variant, `Error` with an empty message and `ConfirmDestroy` from the settings
+static ALL_DIALOGS: [DialogType; 10] = [
+    DialogType::None,
+    DialogType::ApiEndpoint,
+    DialogType::Settings,
+    DialogType::Language,
+    DialogType::NewApp,
+    DialogType::RecentThemes,
+    DialogType::SeaOrmConfig,
+    DialogType::TelemetryConsent,
+    DialogType::ConfirmDestroy { undo: false },
+    DialogType::Error {
+        message: String::new(),
+    },
+];
+
+impl DialogType {
+    /// All dialog types, for tooling that enumerates dialogs (`Error` has an empty message)
+    pub fn all_variants() -> &'static [DialogType] {
+        &ALL_DIALOGS
+    }
+
+    /// The snake_case name of the dialog, same as [`DialogType::as_log_str`]
+    pub fn name(&self) -> &'static str {
+        self.as_log_str()
+    }
+
+    /// A stable snake_case name for the dialog, used as a key in logs and saved state
+    pub fn as_log_str(&self) -> &'static str {
+        match self {
+            DialogType::None => "none",
+            DialogType::ApiEndpoint => "api_endpoint",
+            DialogType::Settings => "settings",
+            DialogType::Language => "language",
+            DialogType::NewApp => "new_app",
+            DialogType::RecentThemes => "recent_themes",
+            DialogType::SeaOrmConfig => "sea_orm_config",
+            DialogType::TelemetryConsent => "telemetry_consent",
+            DialogType::ConfirmDestroy { .. } => "confirm_destroy",
+            DialogType::Error { .. } => "error",
+        }
+    }
 }
 
 /// Settings dialog options
 ///
 /// - `Theme`: Theme selection
 /// - `Language`: Language selection
+/// - `LanguageCodes`: Toggle language codes in the language list
+/// - `Destroy`: Destroy the Rext app, after a confirmation
 /// - `Close`: Close the dialog
-#[derive(Debug, Clone, PartialEq)]
+/// - `LanguageStickySearch`: Toggle keeping the language search between opens
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SettingsOption {
     Theme,
     Language,
+    LanguageCodes,
     Destroy,
     Close,
+    LanguageStickySearch,
+}
+
+/// Every [`SettingsOption`] variant, in the order they're listed and numbered in the settings dialog
+///
+/// New options go at the end, so the digit shortcuts of the existing ones don't change.
+static ALL_SETTINGS_OPTIONS: [SettingsOption; 6] = [
+    SettingsOption::Theme,
+    SettingsOption::Language,
+    SettingsOption::LanguageCodes,
+    SettingsOption::Destroy,
+    SettingsOption::Close,
+    SettingsOption::LanguageStickySearch,
+];
+
+impl SettingsOption {
+    /// All settings options, in the order of the settings dialog
+    pub fn all_variants() -> &'static [SettingsOption] {
+        &ALL_SETTINGS_OPTIONS
+    }
 }
 
 /// The main application which holds the state and logic of the application.
@@ -77,12 +224,30 @@ pub struct App {
     pub current_dialog: DialogType,
     /// Text input buffer for API endpoint name
     pub api_endpoint_input: String,
+    /// API endpoint names previously created in the workspace, oldest first
+    pub endpoint_history: Vec<String>,
+    /// Selected endpoint history suggestion, if cycling with Tab
+    pub history_selected: Option<usize>,
+    /// The typed input that endpoint history suggestions are matched against while cycling
+    pub history_prefix: String,
+    /// Whether the overwrite warning is shown for an existing endpoint name, the next Enter overwrites it
+    pub api_endpoint_overwrite_confirmed: bool,
+    /// Endpoints created with submit and continue since the API endpoint dialog was opened
+    pub api_endpoint_created_count: usize,
     /// Current theme name
     pub current_theme: String,
+    /// Recently used themes, most recent first, at most [`MAX_RECENT_THEMES`]
+    pub recently_used_themes: VecDeque<String>,
+    /// Recent themes dialog list state
+    pub recent_themes_list_state: ListState,
     /// Localization system
     pub localization: Localization,
+    /// The code of the current language (e.g. "en")
+    pub current_language_code: String,
     /// Settings dialog selected index
     pub settings_selected: usize,
+    /// Settings dialog list state, kept in sync with `settings_selected`
+    pub settings_list_state: ListState,
     /// Language dialog search input
     pub language_search: String,
     /// Language dialog selected index
@@ -91,594 +256,2228 @@ pub struct App {
     pub filtered_languages: Vec<(String, String)>,
     /// Language dialog list state
     pub language_list_state: ListState,
-    /// New app dialog selected button (0 = Create, 1 = Cancel)
-    pub new_app_button_selected: usize,
+    /// Number of visible rows in the language list, updated when the dialog renders
+    pub language_list_page_size: usize,
+    /// Show language codes next to display names in the language list
+    pub show_language_codes: bool,
+    /// Keep the language dialog search text and results when it's closed and reopened
+    pub sticky_language_search: bool,
+    /// How many times each dialog was opened, keyed by [`DialogType::as_log_str`]
+    pub dialog_open_counts: HashMap<String, usize>,
+    /// Selected index of list dialogs when they were last closed, keyed by [`DialogType::as_log_str`]
+    pub saved_scroll_positions: HashMap<String, usize>,
+    /// The last error from an action, shown in the error dialog until dismissed
+    pub last_error: Option<String>,
+    /// The dialog the error dialog was opened over, it's shown again once the error is dismissed
+    dialog_before_error: Option<DialogType>,
+    /// Whether the displayed settings differ from the saved ones because a save failed,
+    /// shown as `[modified]` in the status row until the next successful save
+    pub has_unsaved_changes: bool,
+    /// The directory the TUI runs in, an error if the current directory can't be read
+    /// (e.g. it was deleted), in which case the Rext app check is skipped
+    pub current_dir_result: Result<PathBuf, std::io::Error>,
+    /// Undo steps for the latest operations, most recent last, at most [`MAX_UNDO_STEPS`]
+    pub undo_stack: Vec<UndoAction>,
+    /// The index of the focused button in the current dialog
+    /// (e.g. new app dialog: 0 = Create, 1 = Cancel)
+    pub focused_button: usize,
+    /// New app dialog app name input, pre-filled with the current directory name
+    pub new_app_name_input: String,
+    /// Is the new app dialog name input focused? Otherwise the buttons are
+    pub new_app_name_focused: bool,
     /// New app dialog result message
     pub new_app_message: Option<String>,
-    /// Current directory name for display
-    pub current_dir_name: String,
+    /// SeaORM config dialog database URL input
+    pub sea_orm_database_url_input: String,
+    /// SeaORM config dialog output directory input, pre-filled with [`DEFAULT_SEA_ORM_OUTPUT_DIR`]
+    pub sea_orm_output_dir_input: String,
+    /// Is the SeaORM config dialog output directory input focused? Otherwise the database URL is
+    pub sea_orm_output_dir_focused: bool,
+    /// Notifications shown on the main screen, the most recent is displayed,
+    /// at most [`MAX_NOTIFICATIONS`]
+    pub notifications: Vec<Notification>,
+    /// Key presses buffered while typing a multi-key sequence (e.g. `Ctrl+K Ctrl+K`)
+    pub key_sequence_buffer: Vec<(KeyModifiers, KeyCode)>,
+    /// When the buffered key sequence was started, used to time out hanging sequences
+    pub key_sequence_started: Option<Instant>,
+    /// When the app was created, used for the session duration
+    pub session_start: Instant,
+    /// How long [`App::new`] took to initialize the app
+    startup_duration: Duration,
+    /// Whether the terminal is below the minimum size, drawing is paused until it's resized
+    pub terminal_too_small: bool,
+    /// Render plain text without borders or colors for screen readers and braille displays,
+    /// enabled with `REXT_A11Y=1` or [`AppBuilder::accessibility_mode`]
+    pub accessibility_mode: bool,
+    /// How many frames were rendered
+    pub render_frame_count: u64,
+    /// How long the last frame took to render
+    pub last_render_duration: Duration,
+    /// The workspace the TUI was started in
+    pub workspace: WorkspaceInfo,
+    /// The loaded config, `None` if it couldn't be loaded
+    pub config_cache: Option<Arc<Config>>,
+    /// The directory settings, the session and logs are read from and saved to, ~/.rext/ unless
+    /// set with [`AppBuilder::config_dir`], `None` if the home directory can't be found
+    pub config_dir: Option<RextConfigDir>,
+    /// Called with the old and new dialog whenever the current dialog changes
+    pub dialog_change_hook: Option<DialogChangeHook>,
+    /// Where [`App::run`] reads terminal events from, crossterm's `event::read` if `None`
+    pub event_source: Option<EventSource>,
+    /// The rext-core operations used by the app, swappable for tests
+    pub rext_core: Box<dyn RextCoreTrait>,
+    /// Records actions to the local telemetry file, `None` unless the user opted in
+    pub telemetry: Option<TelemetryRecorder>,
+    /// Whether the user was already asked to opt in to telemetry, otherwise it's asked on startup
+    pub telemetry_prompted: bool,
+    /// The rext-core operations run in the session, oldest first, at most [`MAX_OPERATION_RECORDS`]
+    pub operation_records: VecDeque<OperationRecord>,
+    /// The colors of `cached_theme_name`, reused between frames until the theme changes
+    cached_theme: Option<Theme>,
+    /// The theme `cached_theme` was loaded for
+    cached_theme_name: String,
+    /// Whether the theme was cycled in the settings dialog, it's recorded as recently used
+    /// once the dialog is left rather than on every step
+    theme_cycled: bool,
+    /// Whether the working directory contains a Rext app, as of the last check
+    rext_app_exists_cache: bool,
+    /// Whether `rext_app_exists_cache` is stale and is checked again on the next tick
+    /// (e.g. the terminal regained focus, the app may have been changed externally)
+    rext_app_cache_dirty: bool,
 }
 
-/// Theme colors
+/// The rext-core operations used by the TUI
 ///
-/// - `primary`: Accent color for highlights, borders, and interactive elements
-/// - `text`: Regular text color for most content
-/// - `background`: Background color for the entire application
-struct Theme {
-    primary: Color,
-    text: Color,
-    background: Color,
+/// [`DefaultRextCore`] calls the real rext-core functions, tests can inject their own
+/// implementation with [`App::with_mock_rext_core`] to control the results.
+pub trait RextCoreTrait {
+    /// Scaffolds a new Rext app in `dir`
+    fn scaffold_rext_app(&self, dir: &Path) -> Result<(), Box<dyn std::error::Error>>;
+    /// Scaffolds a new Rext app named `name` in `dir`, returning the app's directory
+    ///
+    /// That's `dir` itself if it's named `name`, otherwise a new `name` subdirectory of it.
+    fn scaffold_rext_app_named(
+        &self,
+        dir: &Path,
+        name: &str,
+    ) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let app_dir = app_dir_for(dir, name);
+        std::fs::create_dir_all(&app_dir)?;
+        self.scaffold_rext_app(&app_dir)?;
+        Ok(app_dir)
+    }
+    /// Removes the Rext app in `dir`
+    fn destroy_rext_app(&self, dir: &Path) -> Result<(), Box<dyn std::error::Error>>;
+    /// Deletes the API endpoint named `name`
+    ///
+    /// Does nothing by default, rext-core doesn't create endpoints yet, see `handle_api_endpoint_creation`.
+    fn delete_api_endpoint(&self, _name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+    /// Checks whether `dir` contains a Rext app
+    fn check_for_rext_app(&self, dir: &Path) -> bool;
+    /// Generates SeaORM entities with OpenAPI schema in `dir`, returning the number of entities generated
+    fn generate_sea_orm_entities_with_open_api_schema(
+        &self,
+        dir: &Path,
+    ) -> Result<usize, Box<dyn std::error::Error>>;
 }
 
-/// Macro for creating ratatui styled spans with localization and color
-#[macro_export]
-macro_rules! styled_span {
-    // Create a styled span with localization and color
-    ($localization:expr, $method:ident, $key:expr, $color:expr) => {
-        ratatui::text::Span::styled(
-            $localization.$method($key),
-            ratatui::style::Style::default().fg($color),
-        )
-    };
-    // Create a styled span with localization, color, and additional style modifiers
-    ($localization:expr, $method:ident, $key:expr, $color:expr, $($modifier:ident),+) => {
-        ratatui::text::Span::styled(
-            $localization.$method($key),
-            ratatui::style::Style::default().fg($color)$(.$modifier())+,
-        )
-    };
+/// The directory a Rext app named `name` is scaffolded into from `dir`
+fn app_dir_for(dir: &Path, name: &str) -> PathBuf {
+    if dir.file_name() == Some(std::ffi::OsStr::new(name)) {
+        dir.to_path_buf()
+    } else {
+        dir.join(name)
+    }
 }
 
-/// Macro for creating ratatui Line objects with multiple styled spans
-#[macro_export]
-macro_rules! styled_line {
-    // Create a line with multiple styled spans
-    ($($localization:expr, $method:ident, $key:expr, $color:expr $(, $($modifier:ident),+)?);+ $(;)?) => {
-        ratatui::text::Line::from(vec![
-            $(
-                styled_span!($localization, $method, $key, $color $(, $($modifier),+)?),
-            )+
-        ])
-    };
+/// [`RextCoreTrait`] implementation that calls the real rext-core functions
+///
+/// rext-core works in the current directory, so operations for any other directory switch
+/// to it while they run, see [`core_dir`].
+pub struct DefaultRextCore;
+
+impl RextCoreTrait for DefaultRextCore {
+    fn scaffold_rext_app(&self, dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(core_dir::in_dir(dir, rext_core::scaffold_rext_app)??)
+    }
+
+    fn destroy_rext_app(&self, dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(core_dir::in_dir(dir, rext_core::destroy_rext_app)??)
+    }
+
+    fn check_for_rext_app(&self, dir: &Path) -> bool {
+        core_dir::in_dir(dir, rext_core::check_for_rext_app).unwrap_or(false)
+    }
+
+    fn generate_sea_orm_entities_with_open_api_schema(
+        &self,
+        dir: &Path,
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        Ok(core_dir::in_dir(
+            dir,
+            rext_core::generate_sea_orm_entities_with_open_api_schema,
+        )??)
+    }
 }
 
-impl Default for App {
-    fn default() -> Self {
-        // get the language from the current_localization.toml file
-        let language = load_current_language().unwrap_or_else(|_| "en".to_string());
-        let localization = Localization::new(&language).unwrap_or_else(|_| {
-            Localization::new("en").expect("Failed to load English localization")
-        });
+/// Configurable [`RextCoreTrait`] implementation for tests, it never touches the filesystem
+///
+/// - `app_exists`: What `check_for_rext_app` returns, shared so it can change while the app holds the mock;
+///   scaffolding sets it and a successful destroy clears it
+/// - `destroy_error`: The error `destroy_rext_app` fails with, it succeeds if `None`
+/// - `entity_count`: How many entities `generate_sea_orm_entities_with_open_api_schema` reports
+/// - `generate_error`: The error `generate_sea_orm_entities_with_open_api_schema` fails with,
+///   it succeeds if `None`
+/// - `calls`: The operations called with a directory and the directory, oldest first
+///
+/// Only available in the crate's tests and with the `test-support` feature.
+#[cfg(any(test, feature = "test-support"))]
+#[derive(Debug, Clone, Default)]
+pub struct MockRextCore {
+    pub app_exists: Rc<Cell<bool>>,
+    pub destroy_error: Option<String>,
+    pub entity_count: usize,
+    pub generate_error: Option<String>,
+    pub calls: Rc<RefCell<Vec<(String, PathBuf)>>>,
+}
 
-        Self {
-            running: false,
-            current_dialog: DialogType::None,
-            api_endpoint_input: String::new(),
-            current_theme: "rust".to_string(), // rust is the default theme
-            localization,
-            settings_selected: 0,
-            language_search: String::new(),
-            language_selected: 0,
-            filtered_languages: Vec::new(),
-            language_list_state: ListState::default(),
-            new_app_button_selected: 0,
-            new_app_message: None,
-            current_dir_name: std::env::current_dir()
-                .unwrap_or_else(|_| std::path::PathBuf::from("."))
-                .file_name()
-                .unwrap_or_else(|| std::ffi::OsStr::new("current"))
-                .to_string_lossy()
-                .to_string(),
-        }
+#[cfg(any(test, feature = "test-support"))]
+impl MockRextCore {
+    fn record_call(&self, operation: &str, dir: &Path) {
+        self.calls
+            .borrow_mut()
+            .push((operation.to_string(), dir.to_path_buf()));
     }
 }
 
-impl App {
-    /// Construct a new instance of [`App`].
-    pub fn new() -> Self {
-        let current_theme = load_current_theme().unwrap_or_else(|_| "rust".to_string());
-        let language = load_current_language().unwrap_or_else(|_| "en".to_string());
-        let localization = Localization::new(&language).unwrap_or_else(|_| {
-            // If we can't load localization, create a minimal fallback
-            // This shouldn't happen in normal operation since we ship with en.toml
-            Localization::new("en").expect("Failed to load English localization")
-        });
+#[cfg(any(test, feature = "test-support"))]
+impl RextCoreTrait for MockRextCore {
+    fn scaffold_rext_app(&self, dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        self.record_call("scaffold_rext_app", dir);
+        self.app_exists.set(true);
+        Ok(())
+    }
 
-        Self {
-            running: false,
-            current_dialog: DialogType::None,
-            api_endpoint_input: String::new(),
-            current_theme,
-            localization,
-            settings_selected: 0,
-            language_search: String::new(),
-            language_selected: 0,
-            filtered_languages: Vec::new(),
-            language_list_state: ListState::default(),
-            new_app_button_selected: 0,
-            new_app_message: None,
-            current_dir_name: std::env::current_dir()
-                .unwrap_or_else(|_| std::path::PathBuf::from("."))
-                .file_name()
-                .unwrap_or_else(|| std::ffi::OsStr::new("current"))
-                .to_string_lossy()
-                .to_string(),
+    /// Like the default, without creating the app's directory
+    fn scaffold_rext_app_named(
+        &self,
+        dir: &Path,
+        name: &str,
+    ) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let app_dir = app_dir_for(dir, name);
+        self.scaffold_rext_app(&app_dir)?;
+        Ok(app_dir)
+    }
+
+    fn destroy_rext_app(&self, dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        self.record_call("destroy_rext_app", dir);
+        match &self.destroy_error {
+            Some(error) => Err(error.clone().into()),
+            None => {
+                self.app_exists.set(false);
+                Ok(())
+            }
         }
     }
 
-    /// Run the application's main loop.
-    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<(), RextTuiError> {
-        self.running = true;
-        while self.running {
-            terminal.draw(|frame| self.render(frame))?;
-            self.handle_crossterm_events()?;
+    fn check_for_rext_app(&self, dir: &Path) -> bool {
+        self.record_call("check_for_rext_app", dir);
+        self.app_exists.get()
+    }
+
+    fn generate_sea_orm_entities_with_open_api_schema(
+        &self,
+        dir: &Path,
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        self.record_call("generate_sea_orm_entities_with_open_api_schema", dir);
+        match &self.generate_error {
+            Some(error) => Err(error.clone().into()),
+            None => Ok(self.entity_count),
         }
-        Ok(())
     }
+}
 
-    /// Renders the user interface.
-    /// This is responsible for setting the theme, localizations, and drawing the main app screen
-    fn render(&mut self, frame: &mut Frame) {
-        //
-        // Build Layout
-        // ------------
+/// Why [`App::run`] returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppExitReason {
+    /// The user quit the app
+    Quit,
+}
 
-        // Load colors
-        let (primary_color, text_color, background_color) = self.load_colors();
-        let theme = Theme {
-            primary: primary_color,
-            text: text_color,
-            background: background_color,
-        };
+/// A notification shown on the main screen
+///
+/// - `message`: The localized message to display
+/// - `is_error`: Whether the notification reports a failure
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub message: String,
+    pub is_error: bool,
+}
 
-        // Set background color
-        let background = Block::default().style(Style::default().bg(background_color));
-        frame.render_widget(background, frame.area());
+/// Information about the workspace (current directory) the TUI was started in
+///
+/// - `dir_name`: Name of the current directory, used for display and in messages
+/// - `is_rext_app`: Whether the directory contains a Rext app
+/// - `rext_version`: The rext-core version the app depends on, read from its `Cargo.toml`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkspaceInfo {
+    pub dir_name: String,
+    pub is_rext_app: bool,
+    pub rext_version: Option<String>,
+}
 
-        // Main area
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3), // Top area for button
-                Constraint::Min(0),    // Rest of the screen
-            ])
-            .split(frame.area());
+impl WorkspaceInfo {
+    /// Detects the workspace info for the current directory, checking for a Rext app with
+    /// [`DefaultRextCore`]
+    pub fn detect() -> Self {
+        let current_dir = std::env::current_dir();
+        let is_rext_app = current_dir
+            .as_ref()
+            .is_ok_and(|dir| DefaultRextCore.check_for_rext_app(dir));
+        Self::detect_from(&current_dir, is_rext_app)
+    }
 
-        // Top area with buttons
-        let top_area = chunks[0];
+    /// Detects the workspace info for `current_dir`, or nothing if it can't be read
+    fn detect_from(current_dir: &Result<PathBuf, std::io::Error>, is_rext_app: bool) -> Self {
+        match current_dir {
+            Ok(dir) => Self::detect_in(dir, is_rext_app),
+            Err(_) => Self {
+                dir_name: dir_name(Path::new(".")),
+                is_rext_app: false,
+                rext_version: None,
+            },
+        }
+    }
 
-        // Split top area into left and right sections
-        let top_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Min(0),     // Left side for API endpoint and SeaORM buttons
-                Constraint::Length(30), // Right side for settings button
-            ])
-            .split(top_area);
+    /// Detects the workspace info for `dir`, given whether it contains a Rext app
+    ///
+    /// The Rext app check is left to the caller, so it goes through the app's [`RextCoreTrait`].
+    pub fn detect_in(dir: &Path, is_rext_app: bool) -> Self {
+        Self {
+            dir_name: dir_name(dir),
+            is_rext_app,
+            rext_version: is_rext_app.then(|| read_rext_version(dir)).flatten(),
+        }
+    }
+}
 
-        // Split left side vertically for two buttons
-        let left_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1), // API endpoint button
-                Constraint::Length(1), // SeaORM entities button
-                Constraint::Min(0),    // Remaining space
-            ])
-            .split(top_chunks[0]);
+/// Reads the `rext-core` dependency version from the `Cargo.toml` in `dir`
+fn read_rext_version(dir: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let manifest: toml::Value = toml::from_str(&contents).ok()?;
+    match manifest.get("dependencies")?.get("rext-core")? {
+        toml::Value::String(version) => Some(version.clone()),
+        dependency => dependency.get("version")?.as_str().map(str::to_string),
+    }
+}
 
-        // Left side: "add API endpoint" button
-        let button_text = styled_line!(
-            self.localization, ui, "add_api_endpoint", primary_color, bold;
-            self.localization, ui, "add_api_endpoint_shortcut", text_color
-        );
+/// An undo step for an operation, run with the `undo` key (Ctrl+Z)
+///
+/// - `CreateEndpoint`: Undoes creating the named endpoint by deleting it
+/// - `DestroyApp`: Undoes scaffolding the Rext app in the directory by destroying it
+#[derive(Debug, Clone, PartialEq)]
+pub enum UndoAction {
+    CreateEndpoint(String),
+    DestroyApp(PathBuf),
+}
 
-        let button_paragraph = Paragraph::new(button_text).style(Style::default().fg(text_color));
-        frame.render_widget(
-            button_paragraph,
-            Rect::new(
-                left_chunks[0].x + 1,
-                left_chunks[0].y,
-                left_chunks[0].width,
-                1,
-            ),
-        );
+/// Callback fired on dialog changes, receives the old and new [`DialogType`]
+pub type DialogChangeHook = Box<dyn Fn(&DialogType, &DialogType)>;
 
-        // Left side: "Generate SeaORM Entities" button
-        let seaorm_text = styled_line!(
-            self.localization, ui, "generate_sea_orm_entities", primary_color, bold;
-            self.localization, ui, "generate_sea_orm_entities_shortcut", text_color
-        );
+/// Reads the next terminal event, [`App::run`] waits on it between frames
+pub type EventSource = Box<dyn FnMut() -> std::io::Result<Event>>;
 
-        let seaorm_paragraph = Paragraph::new(seaorm_text).style(Style::default().fg(text_color));
-        frame.render_widget(
-            seaorm_paragraph,
-            Rect::new(
-                left_chunks[1].x + 1,
-                left_chunks[1].y,
-                left_chunks[1].width,
-                1,
-            ),
-        );
+/// Builder for [`App`], used to configure optional behavior before the app starts
+///
+/// The last session is only restored when asked for with [`AppBuilder::with_saved_session`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rext_tui::AppBuilder;
+///
+/// let app = AppBuilder::new()
+///     .with_saved_session()
+///     .on_dialog_change(|old, new| eprintln!("{:?} -> {:?}", old, new))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct AppBuilder {
+    dialog_change_hook: Option<DialogChangeHook>,
+    event_source: Option<EventSource>,
+    snapshot: Option<AppSnapshot>,
+    restore_saved_session: bool,
+    config_dir: Option<RextConfigDir>,
+    missing_key_policy: Option<MissingKeyPolicy>,
+    working_dir: Option<PathBuf>,
+    accessibility_mode: Option<bool>,
+}
 
-        // Right side: settings button
-        let settings_text = styled_line!(
-            self.localization, ui, "settings_title", primary_color, bold;
-            self.localization, ui, "settings_shortcut", text_color
-        );
+impl AppBuilder {
+    /// Creates a new builder with no hooks set
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        let settings_paragraph = Paragraph::new(settings_text)
-            .style(Style::default().fg(text_color))
-            .alignment(Alignment::Right);
-        frame.render_widget(
-            settings_paragraph,
-            Rect::new(
-                top_chunks[1].x,
-                top_chunks[1].y + 1,
-                top_chunks[1].width - 1,
-                1,
-            ),
-        );
+    /// Creates a builder that restores the given session snapshot
+    pub fn from_snapshot(snapshot: AppSnapshot) -> Self {
+        Self {
+            snapshot: Some(snapshot),
+            ..Self::default()
+        }
+    }
 
-        // Bottom area with quit instructions
-        let bottom_area = chunks[1];
-        let quit_instructions = styled_line!(
-            self.localization, msg, "quit_instruction_prefix", text_color;
-            self.localization, key, "quit", primary_color, bold;
-            self.localization, msg, "quit_instruction_middle", text_color;
-            self.localization, key, "quit_combo", primary_color, bold;
-            self.localization, msg, "quit_instruction_suffix", text_color
-        );
+    /// Restores the last session from ~/.rext/session.toml when the app is built, if there is one
+    ///
+    /// A snapshot given with [`AppBuilder::from_snapshot`] takes precedence.
+    pub fn with_saved_session(mut self) -> Self {
+        self.restore_saved_session = true;
+        self
+    }
 
-        let quit_paragraph = Paragraph::new(quit_instructions).alignment(Alignment::Center);
+    /// Reads and saves the settings, session and logs in `dir` instead of ~/.rext/
+    pub fn config_dir(mut self, dir: RextConfigDir) -> Self {
+        self.config_dir = Some(dir);
+        self
+    }
 
-        // Position quit message at bottom of screen
-        let quit_rect = Rect::new(
-            bottom_area.x,
-            bottom_area.y + bottom_area.height - 2,
-            bottom_area.width,
-            1,
-        );
+    /// Sets a hook that fires with the old and new dialog whenever the current dialog changes
+    pub fn on_dialog_change<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&DialogType, &DialogType) + 'static,
+    {
+        self.dialog_change_hook = Some(Box::new(hook));
+        self
+    }
 
-        //
-        // Render App
-        // ----------
-        frame.render_widget(quit_paragraph, quit_rect);
+    /// Reads the terminal events from `source` instead of crossterm, e.g. to drive
+    /// [`App::run`] with a `TestBackend`
+    pub fn event_source<F>(mut self, source: F) -> Self
+    where
+        F: FnMut() -> std::io::Result<Event> + 'static,
+    {
+        self.event_source = Some(Box::new(source));
+        self
+    }
 
-        //
-        // Dialogs
-        // -------
+    /// Sets what localized texts show for missing localization keys, see [`MissingKeyPolicy`]
+    pub fn missing_key_policy(mut self, policy: MissingKeyPolicy) -> Self {
+        self.missing_key_policy = Some(policy);
+        self
+    }
 
-        //
-        // Check for Rext App
-        // ------------------
-        // Open the new app dialog if no app exists
-        let rext_app_exists = rext_core::check_for_rext_app();
-        // If no app exists, open the new app dialog
-        // This is a sort of "infinite loop", as the user can't close the dialog without creating an app.
-        // They can however close the app, so it's fine.
-        if !rext_app_exists {
-            self.current_dialog = DialogType::NewApp;
-        }
+    /// Sets the workspace directory shown in the TUI, instead of the current directory
+    pub fn working_dir(mut self, path: PathBuf) -> Self {
+        self.working_dir = Some(path);
+        self
+    }
 
-        // Render dialog if open
-        if self.current_dialog != DialogType::None {
-            self.render_dialog(frame, theme);
-        }
+    /// Sets whether to render plain text for screen readers, overriding `REXT_A11Y`
+    pub fn accessibility_mode(mut self, enabled: bool) -> Self {
+        self.accessibility_mode = Some(enabled);
+        self
     }
 
-    /// Renders the appropriate dialog based on current_dialog type, via the DialogType enum
-    fn render_dialog(&mut self, frame: &mut Frame, theme: Theme) {
-        match &self.current_dialog {
-            DialogType::ApiEndpoint => self.render_api_endpoint_dialog(frame, theme),
-            DialogType::Settings => self.render_settings_dialog(frame, theme),
-            DialogType::Language => self.render_language_dialog(frame, theme),
-            DialogType::NewApp => self.render_new_app_dialog(frame, theme),
-            DialogType::None => {}
+    /// Builds the [`App`]
+    pub fn build(self) -> App {
+        let mut app = App::new_in(self.working_dir, self.config_dir);
+        if let Some(enabled) = self.accessibility_mode {
+            app.accessibility_mode = enabled;
+        }
+        if let Some(policy) = self.missing_key_policy {
+            app.localization.set_missing_key_policy(policy);
+        }
+        let snapshot = match self.snapshot {
+            Some(snapshot) => Some(snapshot),
+            None if self.restore_saved_session => app
+                .config_dir
+                .as_ref()
+                .and_then(|dir| load_session_snapshot_in(dir).ok()),
+            None => None,
+        };
+        if let Some(snapshot) = snapshot {
+            app.restore_snapshot(snapshot);
         }
+        app.dialog_change_hook = self.dialog_change_hook;
+        app.event_source = self.event_source;
+        app
     }
+}
 
-    /// Renders the API endpoint dialog in the center of the screen
-    ///
-    /// - `frame`: The frame to render the dialog on
-    /// - `t`: The theme to use for the dialog
-    ///
-    /// > This dialog will be used to create a new API endpoint in a Rext app- does nothing right now.
-    /// > **WARNING**: This is a stub, needs to call the rext-core functions to create the API endpoint. TBD.
-    fn render_api_endpoint_dialog(&self, frame: &mut Frame, t: Theme) {
-        let area = frame.area();
+/// Theme colors
+///
+/// - `primary`: Accent color for highlights, borders, and interactive elements
+/// - `text`: Regular text color for most content
+/// - `background`: Background color for the entire application
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Theme {
+    primary: Color,
+    text: Color,
+    background: Color,
+}
 
-        // Calculate dialog size and position (centered)
-        let dialog_width = 50.min(area.width - 4);
-        let dialog_height = 5;
-        let x = (area.width - dialog_width) / 2;
-        let y = (area.height - dialog_height) / 2;
+impl Theme {
+    /// Base style for backgrounds, text in the theme's text color on its background
+    fn base_style(&self) -> Style {
+        Style::default().bg(self.background).fg(self.text)
+    }
+}
 
-        let dialog_rect = Rect::new(x, y, dialog_width, dialog_height);
+/// Read-only snapshot of the [`App`] state needed to render dialogs
+///
+/// Borrowed from the app each frame so the render functions don't need `&mut App`.
+struct AppState<'a> {
+    localization: &'a Localization,
+    current_dialog: &'a DialogType,
+    current_theme: &'a str,
+    recently_used_themes: &'a VecDeque<String>,
+    api_endpoint_input: &'a str,
+    api_endpoint_overwrite_confirmed: bool,
+    api_endpoint_created_count: usize,
+    endpoint_suggestions: Vec<&'a str>,
+    history_selected: Option<usize>,
+    language_search: &'a str,
+    language_selected: usize,
+    filtered_languages: &'a [(String, String)],
+    current_language_code: &'a str,
+    current_language_display: &'a str,
+    show_language_codes: bool,
+    sticky_language_search: bool,
+    focused_button: usize,
+    new_app_name_input: &'a str,
+    new_app_name_focused: bool,
+    new_app_message: Option<&'a str>,
+    sea_orm_database_url_input: &'a str,
+    sea_orm_output_dir_input: &'a str,
+    sea_orm_output_dir_focused: bool,
+    workspace_dir_name: &'a str,
+}
 
-        // Clear the area behind the dialog
-        frame.render_widget(Clear, dialog_rect);
+/// The settings dialog options, in the order of [`SettingsOption::all_variants`]
+fn settings_option_labels(state: &AppState) -> Vec<String> {
+    let on_off = |enabled: bool| {
+        if enabled {
+            state.localization.ui("setting_on")
+        } else {
+            state.localization.ui("setting_off")
+        }
+    };
+    SettingsOption::all_variants()
+        .iter()
+        .map(|option| match option {
+            SettingsOption::Theme => format!(
+                "{}: {}",
+                state.localization.ui("theme_setting"),
+                state.current_theme
+            ),
+            SettingsOption::Language => state.localization.ui("language_setting").to_string(),
+            SettingsOption::LanguageCodes => format!(
+                "{}: {}",
+                state.localization.ui("language_codes_setting"),
+                on_off(state.show_language_codes)
+            ),
+            SettingsOption::Destroy => state.localization.ui("destroy_app_setting").to_string(),
+            SettingsOption::Close => state.localization.ui("close_dialog").to_string(),
+            SettingsOption::LanguageStickySearch => format!(
+                "{}: {}",
+                state.localization.ui("language_sticky_search_setting"),
+                on_off(state.sticky_language_search)
+            ),
+        })
+        .collect()
+}
 
-        // Create dialog block with border
-        let dialog_block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(t.primary))
-            .style(Style::default().bg(t.background));
+/// The hint for picking a settings option by its digit, up to the number of options
+fn settings_digits_instruction(state: &AppState) -> String {
+    state
+        .localization
+        .msg("settings_instruction_digits")
+        .replace("{count}", &SettingsOption::all_variants().len().to_string())
+}
 
-        // Calculate inner area before rendering the block
-        let inner_area = dialog_block.inner(dialog_rect);
+/// A language list row, with its code if they're shown and a marker on the current language
+fn language_label(state: &AppState, code: &str, display: &str) -> String {
+    let mut label = if state.show_language_codes {
+        format!("{:<6} {}", code, display)
+    } else {
+        display.to_string()
+    };
+    if code == state.current_language_code {
+        label.insert_str(0, &state.localization.ui("current_language_marker"));
+    }
+    label
+}
 
-        frame.render_widget(dialog_block, dialog_rect);
+/// The message for how many endpoints were created without closing the endpoint dialog
+fn endpoints_created_message(state: &AppState) -> String {
+    let key = if state.api_endpoint_created_count == 1 {
+        "endpoint_created_one"
+    } else {
+        "endpoints_created"
+    };
+    state
+        .localization
+        .msg(key)
+        .replace("{count}", &state.api_endpoint_created_count.to_string())
+}
 
-        // Split into label and input areas
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1), // Label
-                Constraint::Length(1), // Input
-            ])
-            .split(inner_area);
+/// Loads the endpoint history of the workspace, empty if there's none or it can't be loaded
+fn workspace_endpoint_history(
+    config_dir: Option<&RextConfigDir>,
+    workspace: &Result<PathBuf, std::io::Error>,
+) -> Vec<String> {
+    match (config_dir, workspace) {
+        (Some(dir), Ok(project)) => load_endpoint_history_in(dir, project).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
 
-        // Render label
-        let label = Paragraph::new(self.localization.ui("api_endpoint_name_prompt"))
-            .style(Style::default().fg(t.text));
-        frame.render_widget(label, chunks[0]);
+/// Gets up to 3 endpoint history entries that prefix-match `prefix`, most recent first
+fn matching_endpoints<'a>(history: &'a [String], prefix: &str) -> Vec<&'a str> {
+    if prefix.is_empty() {
+        return Vec::new();
+    }
 
-        // Render input field
-        let input_text = if self.api_endpoint_input.is_empty() {
-            self.localization.ui("input_cursor").to_string()
-        } else {
-            format!(
-                "{}{}",
-                self.api_endpoint_input,
-                self.localization.ui("input_cursor")
-            )
-        };
+    history
+        .iter()
+        .rev()
+        .filter(|endpoint| endpoint.starts_with(prefix) && *endpoint != prefix)
+        .map(|endpoint| endpoint.as_str())
+        .take(3)
+        .collect()
+}
 
-        let input = Paragraph::new(input_text).style(Style::default().fg(t.primary));
-        frame.render_widget(input, chunks[1]);
+/// Locks the file at `path` so only one TUI instance runs at a time
+///
+/// The lock is released when the returned file is dropped.
+fn acquire_instance_lock(path: &Path) -> Result<std::fs::File, RextTuiError> {
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .map_err(RextTuiError::WriteConfigFile)?;
+    file.try_lock_exclusive().map_err(instance_lock_error)?;
+    Ok(file)
+}
+
+/// Maps a failed instance lock to [`RextTuiError::AlreadyRunning`] if another process holds
+/// the lock, any other I/O error to [`RextTuiError::InstanceLock`]
+fn instance_lock_error(error: std::io::Error) -> RextTuiError {
+    let contended = error.kind() == std::io::ErrorKind::WouldBlock
+        || error
+            .raw_os_error()
+            .is_some_and(|code| Some(code) == fs2::lock_contended_error().raw_os_error());
+    if contended {
+        RextTuiError::AlreadyRunning
+    } else {
+        RextTuiError::InstanceLock(error)
     }
+}
 
-    /// Renders the settings dialog
-    ///
-    /// - `frame`: The frame to render the dialog on
-    /// - `t`: The theme to use for the dialog
-    ///
-    /// This dialog displays a list of settings: theme and language selection, with a close option.
-    fn render_settings_dialog(&self, frame: &mut Frame, t: Theme) {
-        let area = frame.area();
+/// The telemetry recorder for the preferences, `None` unless the user opted in
+fn preferences_telemetry(
+    preferences: &UserPreferences,
+    config_dir: Option<&RextConfigDir>,
+) -> Option<TelemetryRecorder> {
+    if !preferences.telemetry_enabled {
+        return None;
+    }
+    Some(TelemetryRecorder::new(config_dir?.telemetry_path()))
+}
 
-        // Calculate dialog size and position (centered)
-        let dialog_width = 60.min(area.width - 4);
-        let dialog_height = 8;
-        let x = (area.width - dialog_width) / 2;
-        let y = (area.height - dialog_height) / 2;
+/// Logs how long an [`App::new`] phase took since `started`, returns the start of the next phase
+fn log_init_phase(phase: &str, started: Instant) -> Instant {
+    log::debug!("init phase '{}': {:?}", phase, started.elapsed());
+    Instant::now()
+}
 
-        let dialog_rect = Rect::new(x, y, dialog_width, dialog_height);
+/// Gets the name of a directory for display
+fn dir_name(dir: &Path) -> String {
+    dir.file_name()
+        .unwrap_or_else(|| std::ffi::OsStr::new("current"))
+        .to_string_lossy()
+        .to_string()
+}
 
-        // Clear the area behind the dialog
-        frame.render_widget(Clear, dialog_rect);
+/// Converts an endpoint name and HTTP method to a preview of the generated handler signature
+///
+/// The name is converted to snake_case and prefixed with the method unless it already is,
+/// e.g. `"users"` + `"GET"` gives `async fn get_users(/* ... */) -> impl Responder`.
+fn endpoint_name_to_fn_signature(name: &str, method: &str) -> String {
+    let mut fn_name = String::new();
+    for c in name.trim().chars() {
+        if c.is_alphanumeric() {
+            fn_name.extend(c.to_lowercase());
+        } else if !fn_name.ends_with('_') {
+            fn_name.push('_');
+        }
+    }
+    let fn_name = fn_name.trim_matches('_');
 
-        // Create dialog block with border
-        let dialog_block = Block::default()
-            .title(self.localization.ui("settings_title"))
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(t.primary))
-            .style(Style::default().bg(t.background));
+    let method = method.to_lowercase();
+    let fn_name = if fn_name.starts_with(&format!("{method}_")) {
+        fn_name.to_string()
+    } else {
+        format!("{method}_{fn_name}")
+    };
 
-        let inner_area = dialog_block.inner(dialog_rect);
-        frame.render_widget(dialog_block, dialog_rect);
+    format!("async fn {fn_name}(/* ... */) -> impl Responder")
+}
 
-        // Settings options
-        let settings_options = vec![
-            format!(
-                "{}: {}",
-                self.localization.ui("theme_setting"),
-                self.current_theme
-            ),
-            self.localization.ui("language_setting").to_string(),
-            self.localization.ui("destroy_app_setting").to_string(),
-            self.localization.ui("close_dialog").to_string(),
-        ];
+/// Keeps the languages whose code or display name contains `search`
+///
+/// Matching ignores case and Unicode normalization differences, see [`fold_for_search`].
+/// An empty search keeps every language, the order of `languages` is preserved.
+pub fn filter_languages(search: &str, languages: Vec<(String, String)>) -> Vec<(String, String)> {
+    let search_term = fold_for_search(search);
+    languages
+        .into_iter()
+        .filter(|(code, display)| {
+            fold_for_search(code).contains(&search_term)
+                || fold_for_search(display).contains(&search_term)
+        })
+        .collect()
+}
 
-        let items: Vec<ListItem> = settings_options
-            .iter()
-            .enumerate()
-            .map(|(i, option)| {
-                let style = if i == self.settings_selected {
-                    Style::default().fg(t.primary).bold()
-                } else {
-                    Style::default().fg(t.text)
-                };
-                ListItem::new(option.clone()).style(style)
-            })
-            .collect();
+/// Moves the language with `code` to the top of an alphabetically sorted language list
+fn move_language_first(languages: &mut [(String, String)], code: &str) {
+    if let Some(index) = languages.iter().position(|(c, _)| c == code) {
+        languages[..=index].rotate_right(1);
+    }
+}
+
+/// Normalizes text for case-insensitive searching
+///
+/// NFD normalization makes precomposed and decomposed characters (e.g. `ü` vs `u` + `◌̈`)
+/// compare equal, then the text is case folded with Unicode-aware lowercasing.
+fn fold_for_search(text: &str) -> String {
+    text.nfd().collect::<String>().to_lowercase()
+}
 
-        let list = List::new(items);
-        frame.render_widget(list, inner_area);
+/// Deletes the last word of `input`, with any separators (e.g. `_`) after it
+///
+/// `"get_user_posts"` becomes `"get_user_"`, and `"get_user_"` becomes `"get_"`.
+fn pop_word(input: &mut String) {
+    let word_end = input.trim_end_matches(|c: char| !c.is_alphanumeric()).len();
+    let word_start = input[..word_end]
+        .rfind(|c: char| !c.is_alphanumeric())
+        .map_or(0, |index| {
+            index + input[index..].chars().next().map_or(0, char::len_utf8)
+        });
+    input.truncate(word_start);
+}
 
-        // Render instruction at the bottom
-        let instruction_rect = Rect::new(
-            dialog_rect.x + 1,
-            dialog_rect.y + dialog_rect.height,
-            dialog_rect.width - 2,
-            1,
-        );
-        let instruction = Paragraph::new(self.localization.msg("settings_instruction"))
-            .style(Style::default().fg(t.text));
-        frame.render_widget(instruction, instruction_rect);
+/// Checks that `name` can be used as the new app's directory name
+///
+/// Returns the localization key of the error message if it can't.
+fn app_name_error(name: &str) -> Option<&'static str> {
+    if name.trim().is_empty() {
+        Some("new_app_name_empty_error")
+    } else if name.contains(['/', '\\']) {
+        Some("new_app_name_slash_error")
+    } else if name == "."
+        || name == ".."
+        || name.trim() != name
+        || name
+            .chars()
+            .any(|c| c.is_control() || matches!(c, ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+    {
+        Some("new_app_name_invalid_error")
+    } else {
+        None
     }
+}
 
-    /// Renders the language selection dialog
-    ///
-    /// - `frame`: The frame to render the dialog on
-    /// - `t`: The theme to use for the dialog
-    ///
-    /// This dialog displays a list of languages, with a search box and a list of languages.
-    fn render_language_dialog(&mut self, frame: &mut Frame, t: Theme) {
-        let area = frame.area();
+/// Minimum width for the new app dialog buttons to sit side by side (two 15 column buttons plus gap)
+const NEW_APP_BUTTONS_MIN_WIDTH: u16 = 36;
 
-        // Calculate dialog size and position (centered)
-        let dialog_width = 60.min(area.width - 4);
-        let dialog_height = 15.min(area.height - 4);
-        let x = (area.width - dialog_width) / 2;
-        let y = (area.height - dialog_height) / 2;
+/// Minimum terminal width for the project name header to be shown
+const HEADER_MIN_WIDTH: u16 = 50;
 
-        let dialog_rect = Rect::new(x, y, dialog_width, dialog_height);
+/// Minimum terminal width for the app to render, smaller terminals show a warning instead
+const MIN_WIDTH: u16 = 40;
 
-        // Clear the area behind the dialog
-        frame.render_widget(Clear, dialog_rect);
+/// Minimum terminal height for the app to render, fits the tallest dialog (new app)
+const MIN_HEIGHT: u16 = 16;
 
-        // Create dialog block with border
-        let dialog_block = Block::default()
-            .title(self.localization.ui("language_dialog_title"))
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(t.primary))
-            .style(Style::default().bg(t.background));
+/// `percent`% of `value`, computed wide so large terminals don't overflow
+fn percent_of(value: u16, percent: u16) -> u16 {
+    (u32::from(value) * u32::from(percent) / 100) as u16
+}
 
-        let inner_area = dialog_block.inner(dialog_rect);
-        frame.render_widget(dialog_block, dialog_rect);
+/// Environment variable pointing to a theme file used instead of the current theme,
+/// see [`config::load_theme_from_file`]
+const THEME_FILE_ENV_VAR: &str = "REXT_THEME_FILE";
 
-        // Split into search box and list
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3), // Search box
-                Constraint::Min(0),    // Language list
-            ])
-            .split(inner_area);
+/// Environment variable that turns on the accessibility mode when set to `1`
+const ACCESSIBILITY_ENV_VAR: &str = "REXT_A11Y";
 
-        // Render search box
-        let search_text = if self.language_search.is_empty() {
-            self.localization
-                .ui("language_search_placeholder")
-                .to_string()
-        } else {
-            format!(
-                "{}{}",
-                self.language_search,
-                self.localization.ui("input_cursor")
-            )
-        };
+/// Whether the accessibility mode is turned on with [`ACCESSIBILITY_ENV_VAR`]
+fn accessibility_env_enabled() -> bool {
+    std::env::var(ACCESSIBILITY_ENV_VAR).is_ok_and(|value| value == "1")
+}
 
-        let search_box = Paragraph::new(search_text)
-            .style(Style::default().fg(t.primary))
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(t.text)),
-            );
-        frame.render_widget(search_box, chunks[0]);
+/// Smallest width a dialog shrinks to before it's limited by the terminal width
+const MIN_DIALOG_WIDTH: u16 = 40;
 
-        // Render language list
-        if self.filtered_languages.is_empty() {
-            let no_results = Paragraph::new(self.localization.ui("no_languages_found"))
-                .style(Style::default().fg(t.text))
-                .alignment(Alignment::Center);
-            frame.render_widget(no_results, chunks[1]);
-        } else {
-            let items: Vec<ListItem> = self
-                .filtered_languages
-                .iter()
-                .enumerate()
-                .map(|(i, (_, display))| {
-                    let style = if i == self.language_selected {
-                        Style::default().fg(t.primary).bold()
-                    } else {
-                        Style::default().fg(t.text)
-                    };
-                    ListItem::new(display.clone()).style(style)
-                })
-                .collect();
+/// Smallest height a dialog shrinks to before it's limited by the terminal height
+const MIN_DIALOG_HEIGHT: u16 = 6;
 
-            let list = List::new(items);
-            self.language_list_state
-                .select(Some(self.language_selected));
-            frame.render_stateful_widget(list, chunks[1], &mut self.language_list_state);
-        }
+/// Centers a dialog in `area`, 70% of the terminal width (at most 80 columns) and `height` rows tall,
+/// no smaller than the `MIN_DIALOG_*` floors and never larger than the terminal
+fn dialog_rect(area: Rect, height: u16) -> Rect {
+    let width = percent_of(area.width, 70)
+        .clamp(MIN_DIALOG_WIDTH, 80)
+        .min(area.width);
+    let height = height.max(MIN_DIALOG_HEIGHT).min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
 
-        // Render instruction at the bottom
-        let instruction_rect = Rect::new(
-            dialog_rect.x + 1,
-            dialog_rect.y + dialog_rect.height,
-            dialog_rect.width - 2,
-            1,
-        );
-        let instruction = Paragraph::new(self.localization.msg("language_instruction"))
-            .style(Style::default().fg(t.text));
-        frame.render_widget(instruction, instruction_rect);
-    }
+/// Renders slower than this (one frame at 60 FPS) are logged in debug builds
+#[cfg(debug_assertions)]
+const SLOW_RENDER_THRESHOLD: Duration = Duration::from_millis(16);
 
-    /// Renders the new app dialog
-    ///
-    /// - `frame`: The frame to render the dialog on
-    /// - `t`: The theme to use for the dialog
-    ///
-    /// This dialog is triggered when no Rext app is found in the current directory.
-    /// It allows the user to create a new Rext app.
-    /// TODO - after creating the app, hide the buttons for clarity.
-    fn render_new_app_dialog(&self, frame: &mut Frame, t: Theme) {
-        let area = frame.area();
+/// Maximum number of operations that can be undone
+pub const MAX_UNDO_STEPS: usize = 10;
 
-        // Calculate dialog size and position (centered)
-        let dialog_width = 70.min(area.width - 4);
-        let dialog_height = 12.min(area.height - 4);
-        let x = (area.width - dialog_width) / 2;
-        let y = (area.height - dialog_height) / 2;
+/// Maximum number of notifications kept, older ones are dropped
+pub const MAX_NOTIFICATIONS: usize = 20;
 
-        let dialog_rect = Rect::new(x, y, dialog_width, dialog_height);
+/// How long a partially typed key sequence is kept before it's discarded
+const KEY_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
 
-        // Clear the area behind the dialog
-        frame.render_widget(Clear, dialog_rect);
+/// Outcome of feeding a key press into the key sequence buffer
+enum KeySequenceStep {
+    /// A sequence completed, holds the action name
+    Complete(String),
+    /// The buffer is the start of a sequence, waiting for more keys
+    Pending,
+    /// The key isn't part of any sequence and should be handled normally
+    Unmatched,
+}
 
-        // Create dialog block with border
-        let dialog_block = Block::default()
-            .title(Line::from(self.localization.ui("new_app_dialog_title")).centered())
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(t.primary))
-            .style(Style::default().bg(t.background));
+/// Terminal input modes that [`App::run`] enables while it runs and disables again when
+/// it returns, writing the escape sequences through the backend
+///
+/// Both methods do nothing by default, so backends that don't talk to a real terminal
+/// (e.g. `TestBackend`) can opt in with an empty `impl`.
+pub trait TerminalModes {
+    /// Enables bracketed paste, so pasted text arrives as a single [`Event::Paste`], focus
+    /// changes, so the app notices [`Event::FocusGained`], and disambiguated key codes, so
+    /// modified keys like Ctrl+Enter are reported
+    fn enable_input_modes(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 
-        let inner_area = dialog_block.inner(dialog_rect);
-        frame.render_widget(dialog_block, dialog_rect);
+    /// Disables the modes enabled by [`TerminalModes::enable_input_modes`]
+    fn disable_input_modes(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 
-        // Layout for dialog content
+    /// Leaves raw mode and the alternate screen, like `ratatui::restore()`
+    ///
+    /// Only called as a safety net when [`App::run`] panics or fails while running.
+    fn restore_terminal(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: std::io::Write> TerminalModes for CrosstermBackend<W> {
+    fn enable_input_modes(&mut self) -> std::io::Result<()> {
+        crossterm::execute!(self, EnableBracketedPaste, EnableFocusChange)?;
+        // Terminals without the kitty keyboard protocol ignore the flags, Windows rejects them
+        let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES;
+        if let Err(e) = crossterm::execute!(self, PushKeyboardEnhancementFlags(flags)) {
+            log::debug!("keyboard enhancement flags not supported: {}", e);
+        }
+        Ok(())
+    }
+
+    fn disable_input_modes(&mut self) -> std::io::Result<()> {
+        if let Err(e) = crossterm::execute!(self, PopKeyboardEnhancementFlags) {
+            log::debug!("keyboard enhancement flags not supported: {}", e);
+        }
+        crossterm::execute!(self, DisableBracketedPaste, DisableFocusChange)
+    }
+
+    fn restore_terminal(&mut self) -> std::io::Result<()> {
+        crossterm::terminal::disable_raw_mode()?;
+        crossterm::execute!(self, LeaveAlternateScreen, crossterm::cursor::Show)
+    }
+}
+
+impl TerminalModes for TestBackend {}
+
+/// Holds the terminal while [`App::run`] runs, disables its input modes again when dropped
+///
+/// If it's dropped while still `running` (the main loop panicked or failed), the whole
+/// terminal is restored as well, so an embedder that doesn't call `ratatui::restore()`
+/// isn't left in raw mode.
+struct TerminalGuard<B: Backend + TerminalModes> {
+    terminal: Terminal<B>,
+    running: bool,
+}
+
+impl<B: Backend + TerminalModes> TerminalGuard<B> {
+    fn enable(mut terminal: Terminal<B>) -> std::io::Result<Self> {
+        terminal.backend_mut().enable_input_modes()?;
+        Ok(Self {
+            terminal,
+            running: true,
+        })
+    }
+}
+
+impl<B: Backend + TerminalModes> Drop for TerminalGuard<B> {
+    fn drop(&mut self) {
+        let backend = self.terminal.backend_mut();
+        if let Err(e) = backend.disable_input_modes() {
+            log::warn!("failed to disable terminal input modes: {}", e);
+        }
+        if self.running {
+            if let Err(e) = backend.restore_terminal() {
+                log::warn!("failed to restore the terminal: {}", e);
+            }
+        }
+    }
+}
+
+/// Number of times a read interrupted by a signal is retried before giving up
+const EVENT_READ_RETRIES: u32 = 3;
+
+/// Reads an event, retrying with exponential backoff when the read is interrupted
+///
+/// Reads interrupted by a signal (e.g. `SIGWINCH` during a terminal resize) are transient,
+/// so only other I/O errors, or running out of retries, become a [`RextTuiError::ReadEvent`].
+fn read_event_with_retry<F>(mut read: F) -> Result<Event, RextTuiError>
+where
+    F: FnMut() -> std::io::Result<Event>,
+{
+    let mut backoff = std::time::Duration::from_millis(10);
+    let mut retries = 0;
+    loop {
+        match read() {
+            Err(e)
+                if e.kind() == std::io::ErrorKind::Interrupted && retries < EVENT_READ_RETRIES =>
+            {
+                std::thread::sleep(backoff);
+                backoff *= 2;
+                retries += 1;
+            }
+            result => return Ok(result?),
+        }
+    }
+}
+
+/// Macro for creating ratatui styled spans with localization and color
+#[macro_export]
+macro_rules! styled_span {
+    // Create a styled span from raw text with foreground and background colors
+    (raw: $text:expr, fg: $fg:expr, bg: $bg:expr) => {
+        ratatui::text::Span::styled(
+            $text,
+            ratatui::style::Style::default().fg($fg).bg($bg),
+        )
+    };
+    // Create a styled span with localization, foreground and background colors
+    ($localization:expr, $method:ident, $key:expr, fg: $fg:expr, bg: $bg:expr) => {
+        ratatui::text::Span::styled(
+            $localization.$method($key),
+            ratatui::style::Style::default().fg($fg).bg($bg),
+        )
+    };
+    // Create a styled span with localization, foreground and background colors, and style modifiers
+    ($localization:expr, $method:ident, $key:expr, fg: $fg:expr, bg: $bg:expr, $($modifier:ident),+) => {
+        ratatui::text::Span::styled(
+            $localization.$method($key),
+            ratatui::style::Style::default().fg($fg).bg($bg)$(.$modifier())+,
+        )
+    };
+    // Create a styled span with localization and color
+    ($localization:expr, $method:ident, $key:expr, $color:expr) => {
+        ratatui::text::Span::styled(
+            $localization.$method($key),
+            ratatui::style::Style::default().fg($color),
+        )
+    };
+    // Create a styled span with localization, color, and additional style modifiers
+    ($localization:expr, $method:ident, $key:expr, $color:expr, $($modifier:ident),+) => {
+        ratatui::text::Span::styled(
+            $localization.$method($key),
+            ratatui::style::Style::default().fg($color)$(.$modifier())+,
+        )
+    };
+}
+
+/// Macro for creating ratatui Line objects with multiple styled spans
+#[macro_export]
+macro_rules! styled_line {
+    // Create a line with multiple styled spans
+    ($($localization:expr, $method:ident, $key:expr, $color:expr $(, $($modifier:ident),+)?);+ $(;)?) => {
+        ratatui::text::Line::from(vec![
+            $(
+                styled_span!($localization, $method, $key, $color $(, $($modifier),+)?),
+            )+
+        ])
+    };
+}
+
+impl Default for App {
+    /// Same as [`App::new`]
+    fn default() -> Self {
+        Self::new_in(None, None)
+    }
+}
+
+impl App {
+    /// Construct a new instance of [`App`].
+    pub fn new() -> Self {
+        Self::new_in(None, None)
+    }
+
+    /// Constructs the app for the workspace in `working_dir`, the current directory if `None`,
+    /// with its settings in `config_dir`, ~/.rext/ if `None`
+    fn new_in(working_dir: Option<PathBuf>, config_dir: Option<RextConfigDir>) -> Self {
+        let init_start = Instant::now();
+
+        let phase_start = Instant::now();
+        let config_dir = config_dir.or_else(|| get_rext_config_dir().ok());
+        let preferences = config_dir
+            .as_ref()
+            .and_then(|dir| load_preferences_in(dir).ok())
+            .unwrap_or_default();
+        let current_theme = preferences.theme.clone();
+        let language = preferences.language.clone();
+        let phase_start = log_init_phase("preferences", phase_start);
+        let localization = Localization::new(&language).unwrap_or_else(|_| {
+            // If we can't load localization, create a minimal fallback
+            // This shouldn't happen in normal operation since we ship with en.toml
+            Localization::new("en").expect("Failed to load English localization")
+        });
+        let phase_start = log_init_phase("localization", phase_start);
+        let current_dir_result = working_dir.map_or_else(std::env::current_dir, Ok);
+        // The Rext app check runs on the first tick, which refreshes the workspace
+        let workspace = WorkspaceInfo::detect_from(&current_dir_result, false);
+        let phase_start = log_init_phase("workspace", phase_start);
+
+        let mut app = Self {
+            running: false,
+            current_dialog: DialogType::None,
+            api_endpoint_input: String::new(),
+            endpoint_history: workspace_endpoint_history(config_dir.as_ref(), &current_dir_result),
+            history_selected: None,
+            history_prefix: String::new(),
+            api_endpoint_overwrite_confirmed: false,
+            api_endpoint_created_count: 0,
+            current_theme,
+            recently_used_themes: preferences.recently_used_themes.clone().into(),
+            recent_themes_list_state: ListState::default(),
+            localization,
+            current_language_code: language,
+            settings_selected: 0,
+            settings_list_state: ListState::default().with_selected(Some(0)),
+            language_search: String::new(),
+            language_selected: 0,
+            filtered_languages: Vec::new(),
+            language_list_state: ListState::default(),
+            language_list_page_size: 10,
+            show_language_codes: true,
+            sticky_language_search: preferences.language_sticky_search,
+            dialog_open_counts: HashMap::new(),
+            saved_scroll_positions: HashMap::new(),
+            last_error: None,
+            dialog_before_error: None,
+            has_unsaved_changes: false,
+            current_dir_result,
+            accessibility_mode: accessibility_env_enabled(),
+            undo_stack: Vec::new(),
+            focused_button: 0,
+            new_app_name_input: workspace.dir_name.clone(),
+            new_app_name_focused: true,
+            new_app_message: None,
+            notifications: Vec::new(),
+            sea_orm_database_url_input: String::new(),
+            sea_orm_output_dir_input: DEFAULT_SEA_ORM_OUTPUT_DIR.to_string(),
+            sea_orm_output_dir_focused: false,
+            key_sequence_buffer: Vec::new(),
+            key_sequence_started: None,
+            session_start: Instant::now(),
+            startup_duration: Duration::ZERO,
+            terminal_too_small: false,
+            render_frame_count: 0,
+            last_render_duration: Duration::ZERO,
+            workspace,
+            config_cache: load_config().ok(),
+            telemetry: preferences_telemetry(&preferences, config_dir.as_ref()),
+            config_dir,
+            dialog_change_hook: None,
+            event_source: None,
+            rext_core: Box::new(DefaultRextCore),
+            operation_records: VecDeque::new(),
+            cached_theme: None,
+            cached_theme_name: String::new(),
+            theme_cycled: false,
+            rext_app_exists_cache: false,
+            rext_app_cache_dirty: true,
+            telemetry_prompted: preferences.telemetry_prompted,
+        };
+        app.validate_current_theme();
+        log_init_phase("config", phase_start);
+
+        app.startup_duration = init_start.elapsed();
+        log::debug!("init total: {:?}", app.startup_duration);
+        app
+    }
+
+    /// Replaces the rext-core operations, e.g. with a mock in tests
+    pub fn with_mock_rext_core(mut self, mock: Box<dyn RextCoreTrait>) -> Self {
+        self.rext_core = mock;
+        self.rext_app_cache_dirty = true;
+        self
+    }
+
+    /// Run the application's main loop.
+    ///
+    /// Works with any ratatui [`Backend`] that implements [`TerminalModes`], e.g.
+    /// `ratatui::init()` or a `Terminal<TestBackend>`.
+    ///
+    /// Callers should still call `ratatui::restore()` once this returns. If the main loop
+    /// panics or fails, the terminal is restored through [`TerminalModes::restore_terminal`]
+    /// as a safety net.
+    ///
+    /// Bracketed paste is enabled while running so pasted text arrives as [`Event::Paste`],
+    /// focus changes are enabled so the Rext app is checked again on [`Event::FocusGained`].
+    /// Events are read with crossterm unless set with [`AppBuilder::event_source`].
+    pub fn run<B: Backend + TerminalModes>(
+        mut self,
+        terminal: Terminal<B>,
+    ) -> Result<AppExitReason, RextTuiError> {
+        // Held until run returns, dropping the file releases the lock
+        let _lock = acquire_instance_lock(&self.rext_config_dir()?.lock_path())?;
+        let mut guard = TerminalGuard::enable(terminal)?;
+        self.running = true;
+        self.ask_for_telemetry_consent();
+        let result = self.run_loop(&mut guard.terminal);
+        guard.running = self.running;
+        drop(guard);
+        if result.is_ok() {
+            let saved = self
+                .rext_config_dir()
+                .and_then(|dir| save_session_snapshot_in(&dir, &self.snapshot()));
+            if let Err(e) = saved {
+                log::warn!("failed to save session: {}", e);
+            }
+        }
+        result.map(|_| AppExitReason::Quit)
+    }
+
+    /// The directory the app reads and saves its settings in, see [`App::config_dir`]
+    fn rext_config_dir(&self) -> Result<RextConfigDir, RextTuiError> {
+        match &self.config_dir {
+            Some(dir) => Ok(dir.clone()),
+            None => get_rext_config_dir(),
+        }
+    }
+
+    /// Asks the user to opt in to telemetry if they weren't asked yet, runs once when the app
+    /// starts, before anything else is shown
+    fn ask_for_telemetry_consent(&mut self) {
+        if !self.telemetry_prompted {
+            self.focused_button = 1; // Default to No, telemetry is opt-in
+            self.set_dialog(DialogType::TelemetryConsent);
+        }
+    }
+
+    /// Ticks, draws and handles events until the app stops running
+    fn run_loop<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<(), RextTuiError> {
+        while self.running {
+            self.on_tick();
+            // The size warning stays on screen until a resize, there's nothing new to draw
+            if !self.terminal_too_small {
+                terminal.draw(|frame| self.render_timed(frame))?;
+            }
+            self.handle_crossterm_events()?;
+        }
+        Ok(())
+    }
+
+    /// Updates app state that isn't driven by events, runs once per loop before rendering.
+    fn on_tick(&mut self) {
+        // Show the last error over whatever dialog is open
+        if let Some(message) = &self.last_error {
+            if !matches!(self.current_dialog, DialogType::Error { .. }) {
+                self.dialog_before_error = Some(self.current_dialog.clone());
+                self.set_dialog(DialogType::Error {
+                    message: message.clone(),
+                });
+            }
+        }
+
+        //
+        // Check for Rext App
+        // ------------------
+        // If no app exists, open the new app dialog, unless another dialog is already open
+        // (e.g. the user opened Settings to change the language during the new app flow).
+        // This is a sort of "infinite loop", as the user can't close the dialog without creating an app.
+        // They can however close the app, so it's fine.
+        // Without a readable current directory there's nothing to check, so the dialog isn't shown.
+        // The result is cached until it's marked dirty (rext-core operations, focus regained),
+        // the workspace info is refreshed with it.
+        if self.rext_app_cache_dirty {
+            if let Ok(dir) = &self.current_dir_result {
+                self.rext_app_exists_cache = self.rext_core.check_for_rext_app(dir);
+                self.workspace = WorkspaceInfo::detect_in(dir, self.rext_app_exists_cache);
+                self.rext_app_cache_dirty = false;
+            }
+        }
+        if self.current_dialog == DialogType::None
+            && self.current_dir_result.is_ok()
+            && !self.rext_app_exists_cache
+        {
+            self.set_dialog(DialogType::NewApp);
+        }
+
+        // Drop key sequences that were started but never finished
+        if self.key_sequence_timed_out() {
+            self.clear_key_sequence();
+        }
+    }
+
+    /// Renders a frame, counting it and measuring how long it took
+    fn render_timed(&mut self, frame: &mut Frame) {
+        let render_start = Instant::now();
+        self.render(frame);
+        self.last_render_duration = render_start.elapsed();
+        self.render_frame_count += 1;
+
+        #[cfg(debug_assertions)]
+        if self.last_render_duration > SLOW_RENDER_THRESHOLD {
+            log::warn!("Slow render: {:?}", self.last_render_duration);
+        }
+    }
+
+    /// The number of rendered frames and how long the last one took
+    pub fn render_stats(&self) -> (u64, Duration) {
+        (self.render_frame_count, self.last_render_duration)
+    }
+
+    /// The rext-core operations run in the session, oldest first
+    pub fn operation_log(&self) -> &VecDeque<OperationRecord> {
+        &self.operation_records
+    }
+
+    /// Renders the user interface.
+    /// This is responsible for setting the theme, localizations, and drawing the main app screen
+    fn render(&mut self, frame: &mut Frame) {
+        if self.accessibility_mode {
+            self.render_accessible(frame);
+            return;
+        }
+
+        //
+        // Build Layout
+        // ------------
+
+        // Load colors
+        let theme = self.theme();
+        let (primary_color, text_color) = (theme.primary, theme.text);
+
+        // Set background color
+        let background = Block::default().style(theme.base_style());
+        frame.render_widget(background, frame.area());
+
+        // Only show a warning on terminals too small for the layout and dialogs
+        let area = frame.area();
+        self.terminal_too_small = area.width < MIN_WIDTH || area.height < MIN_HEIGHT;
+        if self.terminal_too_small {
+            let warning = Paragraph::new(
+                self.localization
+                    .msg("terminal_too_small")
+                    .replace("{width}", &MIN_WIDTH.to_string())
+                    .replace("{height}", &MIN_HEIGHT.to_string()),
+            )
+            .style(Style::default().fg(primary_color))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+            frame.render_widget(warning, area);
+            return;
+        }
+
+        // The project name header is only shown on terminals wide enough for it
+        let show_header = frame.area().width >= HEADER_MIN_WIDTH;
+
+        // Main area
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Top area for button
+                Constraint::Length(if show_header {
+                    block_text::HEIGHT + 1
+                } else {
+                    0
+                }), // Project name header
+                Constraint::Min(0),    // Rest of the screen
+            ])
+            .split(frame.area());
+
+        // Top area with buttons
+        let top_area = chunks[0];
+
+        // Split top area into left and right sections
+        let top_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(0),     // Left side for API endpoint and SeaORM buttons
+                Constraint::Length(30), // Right side for settings button
+            ])
+            .split(top_area);
+
+        // Split left side vertically for two buttons
+        let left_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // API endpoint button
+                Constraint::Length(1), // SeaORM entities button
+                Constraint::Min(0),    // Remaining space
+            ])
+            .split(top_chunks[0]);
+
+        // Left side: "add API endpoint" button
+        let button_text = styled_line!(
+            self.localization, ui, "add_api_endpoint", primary_color, bold;
+            self.localization, ui, "add_api_endpoint_shortcut", text_color
+        );
+
+        let button_paragraph = Paragraph::new(button_text).style(Style::default().fg(text_color));
+        frame.render_widget(
+            button_paragraph,
+            Rect::new(
+                left_chunks[0].x + 1,
+                left_chunks[0].y,
+                left_chunks[0].width,
+                1,
+            ),
+        );
+
+        // Left side: "Generate SeaORM Entities" button
+        let seaorm_text = styled_line!(
+            self.localization, ui, "generate_sea_orm_entities", primary_color, bold;
+            self.localization, ui, "generate_sea_orm_entities_shortcut", text_color
+        );
+
+        let seaorm_paragraph = Paragraph::new(seaorm_text).style(Style::default().fg(text_color));
+        frame.render_widget(
+            seaorm_paragraph,
+            Rect::new(
+                left_chunks[1].x + 1,
+                left_chunks[1].y,
+                left_chunks[1].width,
+                1,
+            ),
+        );
+
+        // Right side: settings button
+        let settings_text = styled_line!(
+            self.localization, ui, "settings_title", primary_color, bold;
+            self.localization, ui, "settings_shortcut", text_color
+        );
+
+        let settings_paragraph = Paragraph::new(settings_text)
+            .style(Style::default().fg(text_color))
+            .alignment(Alignment::Right);
+        frame.render_widget(
+            settings_paragraph,
+            Rect::new(
+                top_chunks[1].x,
+                top_chunks[1].y + 1,
+                top_chunks[1].width - 1,
+                1,
+            ),
+        );
+
+        // Project name header, in block letters if they fit or plain text otherwise
+        if show_header {
+            let header_area = chunks[1];
+            let dir_name = &self.workspace.dir_name;
+            let header = if block_text::width(dir_name) <= header_area.width {
+                let rows = block_text::render(dir_name);
+                Paragraph::new(rows.into_iter().map(Line::from).collect::<Vec<_>>())
+            } else {
+                Paragraph::new(dir_name.as_str()).bold()
+            };
+            frame.render_widget(
+                header
+                    .style(Style::default().fg(primary_color))
+                    .alignment(Alignment::Center),
+                header_area,
+            );
+        }
+
+        // Bottom area with quit instructions
+        let bottom_area = chunks[2];
+        let quit_instructions = styled_line!(
+            self.localization, msg, "quit_instruction_prefix", text_color;
+            self.localization, key_pretty, "quit", primary_color, bold;
+            self.localization, msg, "quit_instruction_middle", text_color;
+            self.localization, key_pretty, "quit_combo", primary_color, bold;
+            self.localization, msg, "quit_instruction_suffix", text_color
+        );
+
+        let quit_paragraph = Paragraph::new(quit_instructions).alignment(Alignment::Center);
+
+        // Position quit message at bottom of screen
+        let quit_rect = Rect::new(
+            bottom_area.x,
+            (bottom_area.y + bottom_area.height).saturating_sub(2),
+            bottom_area.width,
+            1,
+        );
+
+        //
+        // Render App
+        // ----------
+        frame.render_widget(quit_paragraph, quit_rect);
+
+        // Show the rext-core version of the app at the right of the status row
+        if let Some(version) = &self.workspace.rext_version {
+            let version_paragraph = Paragraph::new(
+                self.localization
+                    .ui("rext_version_label")
+                    .replace("{version}", version),
+            )
+            .style(Style::default().fg(text_color))
+            .alignment(Alignment::Right);
+            frame.render_widget(
+                version_paragraph,
+                Rect::new(
+                    quit_rect.x,
+                    quit_rect.y,
+                    quit_rect.width.saturating_sub(1),
+                    1,
+                ),
+            );
+        }
+
+        // Show warnings about the working directory and unsaved settings at the left of the status row
+        let status_warnings = self.status_warnings();
+        if !status_warnings.is_empty() {
+            let warnings_paragraph =
+                Paragraph::new(status_warnings.join(" ")).style(Style::default().fg(Color::Yellow));
+            frame.render_widget(
+                warnings_paragraph,
+                Rect::new(
+                    quit_rect.x + 1,
+                    quit_rect.y,
+                    quit_rect.width.saturating_sub(1),
+                    1,
+                ),
+            );
+        }
+
+        // Show the latest notification just above the quit instructions
+        if let Some(notification) = self.notifications.last() {
+            let notification_style = if notification.is_error {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            let notification_paragraph = Paragraph::new(notification.message.as_str())
+                .style(notification_style)
+                .alignment(Alignment::Center);
+            let notification_rect = Rect::new(
+                bottom_area.x,
+                (bottom_area.y + bottom_area.height).saturating_sub(3),
+                bottom_area.width,
+                1,
+            );
+            frame.render_widget(notification_paragraph, notification_rect);
+        }
+
+        //
+        // Dialogs
+        // -------
+
+        // Render dialog if open
+        if self.current_dialog != DialogType::None {
+            // The list state is taken out while the dialog state borrows the app
+            let mut list_state = std::mem::take(self.dialog_list_state_mut());
+            let mut language_page_size = self.language_list_page_size;
+            Self::render_dialog(
+                &self.dialog_state(),
+                &mut list_state,
+                &mut language_page_size,
+                frame,
+                &theme,
+            );
+            self.language_list_page_size = language_page_size;
+            *self.dialog_list_state_mut() = list_state;
+        }
+    }
+
+    /// Borrows the state the current dialog is rendered from
+    fn dialog_state(&self) -> AppState<'_> {
+        let prefix = if self.history_selected.is_some() {
+            &self.history_prefix
+        } else {
+            &self.api_endpoint_input
+        };
+        AppState {
+            localization: &self.localization,
+            current_dialog: &self.current_dialog,
+            current_theme: &self.current_theme,
+            recently_used_themes: &self.recently_used_themes,
+            api_endpoint_input: &self.api_endpoint_input,
+            api_endpoint_overwrite_confirmed: self.api_endpoint_overwrite_confirmed,
+            api_endpoint_created_count: self.api_endpoint_created_count,
+            endpoint_suggestions: matching_endpoints(&self.endpoint_history, prefix),
+            history_selected: self.history_selected,
+            language_search: &self.language_search,
+            language_selected: self.language_selected,
+            filtered_languages: &self.filtered_languages,
+            current_language_code: &self.current_language_code,
+            current_language_display: self.current_language_display(),
+            show_language_codes: self.show_language_codes,
+            sticky_language_search: self.sticky_language_search,
+            focused_button: self.focused_button,
+            new_app_name_input: &self.new_app_name_input,
+            new_app_name_focused: self.new_app_name_focused,
+            new_app_message: self.new_app_message.as_deref(),
+            sea_orm_database_url_input: &self.sea_orm_database_url_input,
+            sea_orm_output_dir_input: &self.sea_orm_output_dir_input,
+            sea_orm_output_dir_focused: self.sea_orm_output_dir_focused,
+            workspace_dir_name: &self.workspace.dir_name,
+        }
+    }
+
+    /// The scroll/selection state of the current dialog's list
+    fn dialog_list_state_mut(&mut self) -> &mut ListState {
+        match self.current_dialog {
+            DialogType::Settings => &mut self.settings_list_state,
+            DialogType::RecentThemes => &mut self.recent_themes_list_state,
+            _ => &mut self.language_list_state,
+        }
+    }
+
+    /// Renders the accessibility mode screen, plain text without borders or colors
+    ///
+    /// The open dialog replaces the main screen, its options are listed as numbered items.
+    fn render_accessible(&self, frame: &mut Frame) {
+        let lines = if self.current_dialog == DialogType::None {
+            self.accessible_main_screen_lines()
+        } else {
+            let selected = match self.current_dialog {
+                DialogType::Settings => self.settings_list_state.selected(),
+                DialogType::RecentThemes => self.recent_themes_list_state.selected(),
+                _ => None,
+            };
+            Self::accessible_dialog_lines(&self.dialog_state(), selected)
+        };
+
+        let paragraph = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
+            .style(Style::default().fg(Color::Reset).bg(Color::Reset))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, frame.area());
+    }
+
+    /// The main screen as plain text lines, in the order they appear on screen
+    fn accessible_main_screen_lines(&self) -> Vec<String> {
+        let l = &self.localization;
+        let mut lines = vec![
+            format!(
+                "{}{}",
+                l.ui("add_api_endpoint"),
+                l.ui("add_api_endpoint_shortcut")
+            ),
+            format!(
+                "{}{}",
+                l.ui("generate_sea_orm_entities"),
+                l.ui("generate_sea_orm_entities_shortcut")
+            ),
+            format!("{}{}", l.ui("settings_title"), l.ui("settings_shortcut")),
+            self.workspace.dir_name.clone(),
+        ];
+        if let Some(notification) = self.notifications.last() {
+            lines.push(notification.message.clone());
+        }
+        lines.push(format!(
+            "{}{}{}{}{}",
+            l.msg("quit_instruction_prefix"),
+            l.key_pretty("quit"),
+            l.msg("quit_instruction_middle"),
+            l.key_pretty("quit_combo"),
+            l.msg("quit_instruction_suffix")
+        ));
+        if let Some(version) = &self.workspace.rext_version {
+            lines.push(l.ui("rext_version_label").replace("{version}", version));
+        }
+        lines.extend(self.status_warnings());
+        lines
+    }
+
+    /// Warnings shown in the status row: an unreadable working directory and unsaved settings
+    fn status_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.current_dir_result.is_err() {
+            warnings.push(self.localization.ui("working_dir_unavailable").into_owned());
+        }
+        if self.has_unsaved_changes {
+            warnings.push(self.localization.ui("modified_indicator").into_owned());
+        }
+        warnings
+    }
+
+    /// The open dialog as plain text lines, options numbered and the `selected` one marked
+    ///
+    /// `selected` is the selected row of the dialog's list, for the settings and recent themes dialogs.
+    fn accessible_dialog_lines(state: &AppState, selected: Option<usize>) -> Vec<String> {
+        let l = state.localization;
+        let selected_suffix = l.ui("accessible_selected_suffix");
+        let menu = |items: Vec<String>, selected: Option<usize>| -> Vec<String> {
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let suffix = if selected == Some(i) {
+                        selected_suffix.as_ref()
+                    } else {
+                        ""
+                    };
+                    format!("{}. {}{}", i + 1, item, suffix)
+                })
+                .collect()
+        };
+        let with_cursor = |input: &str| format!("{}{}", input, l.ui("input_cursor"));
+
+        let mut lines = Vec::new();
+        match state.current_dialog {
+            DialogType::ApiEndpoint => {
+                lines.push(l.ui("api_endpoint_name_prompt").into_owned());
+                lines.push(with_cursor(state.api_endpoint_input));
+                if state.api_endpoint_created_count > 0 {
+                    lines.push(endpoints_created_message(state));
+                }
+                if state.api_endpoint_overwrite_confirmed {
+                    lines.push(
+                        l.msg("endpoint_exists_warning")
+                            .replace("{name}", state.api_endpoint_input),
+                    );
+                } else if !state.api_endpoint_input.is_empty() {
+                    lines.push(endpoint_name_to_fn_signature(
+                        state.api_endpoint_input,
+                        "GET",
+                    ));
+                }
+                let suggestions = state
+                    .endpoint_suggestions
+                    .iter()
+                    .map(|suggestion| suggestion.to_string())
+                    .collect();
+                lines.extend(menu(suggestions, state.history_selected));
+            }
+            DialogType::Settings => {
+                lines.push(l.ui("settings_title").into_owned());
+                lines.extend(menu(settings_option_labels(state), selected));
+                lines.push(
+                    l.ui("settings_footer")
+                        .replace("{theme}", state.current_theme)
+                        .replace("{language}", state.current_language_display),
+                );
+                lines.push(l.msg("settings_instruction").into_owned());
+                lines.push(settings_digits_instruction(state));
+            }
+            DialogType::Language => {
+                lines.push(l.ui("language_dialog_title").into_owned());
+                if state.language_search.is_empty() {
+                    lines.push(l.ui("language_search_placeholder").into_owned());
+                } else {
+                    lines.push(with_cursor(state.language_search));
+                }
+                if state.filtered_languages.is_empty() {
+                    lines.push(l.ui("no_languages_found").into_owned());
+                } else {
+                    let languages = state
+                        .filtered_languages
+                        .iter()
+                        .map(|(code, display)| language_label(state, code, display))
+                        .collect();
+                    lines.extend(menu(languages, Some(state.language_selected)));
+                }
+                lines.push(l.msg("language_instruction").into_owned());
+            }
+            DialogType::NewApp => {
+                lines.push(l.ui("new_app_dialog_title").into_owned());
+                let name = if state.new_app_name_focused {
+                    with_cursor(state.new_app_name_input)
+                } else {
+                    state.new_app_name_input.to_string()
+                };
+                lines.push(format!("{} {}", l.ui("new_app_name_prompt"), name));
+                if let Some(error_key) = app_name_error(state.new_app_name_input) {
+                    lines.push(l.ui(error_key).into_owned());
+                }
+                lines.push(l.ui("new_app_no_app_detected").into_owned());
+                lines.push(l.ui("new_app_dialog_prompt").into_owned());
+                let buttons = vec![
+                    l.ui("new_app_create_button").into_owned(),
+                    l.ui("new_app_cancel_button").into_owned(),
+                ];
+                let focused = (!state.new_app_name_focused).then_some(state.focused_button);
+                lines.extend(menu(buttons, focused));
+                if let Some(message) = state.new_app_message {
+                    lines.push(message.to_string());
+                }
+                lines.push(l.msg("new_app_instruction").into_owned());
+            }
+            DialogType::RecentThemes => {
+                lines.push(l.ui("recent_themes_title").into_owned());
+                if state.recently_used_themes.is_empty() {
+                    lines.push(l.ui("no_recent_themes").into_owned());
+                } else {
+                    let themes = state.recently_used_themes.iter().cloned().collect();
+                    lines.extend(menu(themes, selected));
+                }
+                lines.push(l.msg("recent_themes_instruction").into_owned());
+            }
+            DialogType::SeaOrmConfig => {
+                lines.push(l.ui("sea_orm_config_title").into_owned());
+                for (prompt, input, focused) in [
+                    (
+                        "sea_orm_database_url_prompt",
+                        state.sea_orm_database_url_input,
+                        !state.sea_orm_output_dir_focused,
+                    ),
+                    (
+                        "sea_orm_output_dir_prompt",
+                        state.sea_orm_output_dir_input,
+                        state.sea_orm_output_dir_focused,
+                    ),
+                ] {
+                    lines.push(l.ui(prompt).into_owned());
+                    lines.push(if focused {
+                        with_cursor(input)
+                    } else {
+                        input.to_string()
+                    });
+                }
+                lines.push(l.msg("sea_orm_config_instruction").into_owned());
+            }
+            DialogType::TelemetryConsent => {
+                lines.push(l.ui("telemetry_consent_title").into_owned());
+                lines.push(l.ui("telemetry_consent_prompt").into_owned());
+                let buttons = vec![
+                    l.ui("telemetry_consent_yes").into_owned(),
+                    l.ui("telemetry_consent_no").into_owned(),
+                ];
+                lines.extend(menu(buttons, Some(state.focused_button)));
+            }
+            DialogType::ConfirmDestroy { .. } => {
+                lines.push(l.ui("destroy_app_confirm_title").into_owned());
+                lines.push(
+                    l.ui("destroy_app_confirm_prompt")
+                        .replace("{dir_name}", state.workspace_dir_name),
+                );
+                let buttons = vec![
+                    l.ui("destroy_app_confirm_yes").into_owned(),
+                    l.ui("destroy_app_confirm_no").into_owned(),
+                ];
+                lines.extend(menu(buttons, Some(state.focused_button)));
+            }
+            DialogType::Error { message } => {
+                lines.push(l.ui("error_dialog_title").into_owned());
+                lines.push(message.clone());
+                lines.extend(menu(
+                    vec![l.ui("error_dialog_ok_button").into_owned()],
+                    Some(0),
+                ));
+            }
+            DialogType::None => {}
+        }
+        lines
+    }
+
+    /// Renders the appropriate dialog based on current_dialog type, via the DialogType enum
+    fn render_dialog(
+        state: &AppState,
+        list_state: &mut ListState,
+        language_page_size: &mut usize,
+        frame: &mut Frame,
+        theme: &Theme,
+    ) {
+        match state.current_dialog {
+            DialogType::ApiEndpoint => Self::render_api_endpoint_dialog(state, frame, theme),
+            DialogType::Settings => Self::render_settings_dialog(state, list_state, frame, theme),
+            DialogType::Language => {
+                *language_page_size = Self::render_language_dialog(state, list_state, frame, theme);
+            }
+            DialogType::NewApp => Self::render_new_app_dialog(state, frame, theme),
+            DialogType::RecentThemes => {
+                Self::render_recent_themes_dialog(state, list_state, frame, theme)
+            }
+            DialogType::SeaOrmConfig => Self::render_sea_orm_config_dialog(state, frame, theme),
+            DialogType::TelemetryConsent => {
+                Self::render_telemetry_consent_dialog(state, frame, theme)
+            }
+            DialogType::ConfirmDestroy { .. } => {
+                Self::render_confirm_destroy_dialog(state, frame, theme)
+            }
+            DialogType::Error { message } => {
+                Self::render_error_dialog(message, state, frame, theme)
+            }
+            DialogType::None => {}
+        }
+    }
+
+    /// Renders the API endpoint dialog in the center of the screen
+    ///
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    ///
+    /// > This dialog will be used to create a new API endpoint in a Rext app- does nothing right now.
+    /// > **WARNING**: This is a stub, needs to call the rext-core functions to create the API endpoint. TBD.
+    fn render_api_endpoint_dialog(state: &AppState, frame: &mut Frame, t: &Theme) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), tall enough for its content
+        let show_created_count = state.api_endpoint_created_count > 0;
+        let dialog_height = if state.api_endpoint_overwrite_confirmed {
+            10
+        } else {
+            8
+        } + u16::from(show_created_count);
+        let dialog_rect = dialog_rect(area, dialog_height);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let dialog_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        // Calculate inner area before rendering the block
+        let inner_area = dialog_block.inner(dialog_rect);
+
+        frame.render_widget(dialog_block, dialog_rect);
+
+        // Split into label and input areas
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),                             // Label
+                Constraint::Length(3),                             // Input box
+                Constraint::Length(u16::from(show_created_count)), // Created endpoints counter
+                Constraint::Min(1), // Function signature preview or overwrite warning
+            ])
+            .split(inner_area);
+
+        // Render label
+        let label = Paragraph::new(state.localization.ui("api_endpoint_name_prompt"))
+            .style(Style::default().fg(t.text));
+        frame.render_widget(label, chunks[0]);
+
+        // Render input field
+        let input_text = if state.api_endpoint_input.is_empty() {
+            state.localization.ui("input_cursor").to_string()
+        } else {
+            format!(
+                "{}{}",
+                state.api_endpoint_input,
+                state.localization.ui("input_cursor")
+            )
+        };
+
+        // The input is the only focusable element, so its box always has the focused border
+        let input = Paragraph::new(input_text)
+            .style(Style::default().fg(t.primary))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(t.primary)),
+            );
+        frame.render_widget(input, chunks[1]);
+
+        // Render how many endpoints were created without closing the dialog
+        if show_created_count {
+            let created = Paragraph::new(endpoints_created_message(state))
+                .style(Style::default().fg(Color::Green));
+            frame.render_widget(created, chunks[2]);
+        }
+
+        // Warn before overwriting an existing endpoint, otherwise preview the handler
+        if state.api_endpoint_overwrite_confirmed {
+            let warning = Paragraph::new(
+                state
+                    .localization
+                    .msg("endpoint_exists_warning")
+                    .replace("{name}", state.api_endpoint_input),
+            )
+            .style(Style::default().fg(Color::Yellow))
+            .wrap(Wrap { trim: true });
+            frame.render_widget(warning, chunks[3]);
+        } else if !state.api_endpoint_input.is_empty() {
+            let preview = Paragraph::new(endpoint_name_to_fn_signature(
+                state.api_endpoint_input,
+                "GET",
+            ))
+            .style(Style::default().fg(t.text).dim());
+            frame.render_widget(preview, chunks[3]);
+        }
+
+        // Render endpoint history suggestions below the dialog
+        let suggestions = &state.endpoint_suggestions;
+        if !suggestions.is_empty() {
+            let suggestions_y = dialog_rect.y + dialog_rect.height;
+            let suggestions_height =
+                (suggestions.len() as u16 + 2).min(area.height.saturating_sub(suggestions_y));
+            let suggestions_rect = Rect::new(
+                dialog_rect.x,
+                suggestions_y,
+                dialog_rect.width,
+                suggestions_height,
+            );
+
+            frame.render_widget(Clear, suggestions_rect);
+
+            let items: Vec<ListItem> = suggestions
+                .iter()
+                .enumerate()
+                .map(|(i, suggestion)| {
+                    let style = if state.history_selected == Some(i) {
+                        Style::default().fg(t.primary).bold()
+                    } else {
+                        Style::default().fg(t.text)
+                    };
+                    ListItem::new(suggestion.to_string()).style(style)
+                })
+                .collect();
+
+            let list = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(t.text))
+                    .style(t.base_style()),
+            );
+            frame.render_widget(list, suggestions_rect);
+        }
+    }
+
+    /// Renders the settings dialog
+    ///
+    /// - `state`: The app state to render
+    /// - `list_state`: The scroll/selection state of the settings list
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    ///
+    /// This dialog displays a list of settings: theme and language selection, with a close option.
+    fn render_settings_dialog(
+        state: &AppState,
+        list_state: &mut ListState,
+        frame: &mut Frame,
+        t: &Theme,
+    ) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), tall enough for its content
+        let dialog_rect = dialog_rect(area, 8);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let footer = state
+            .localization
+            .ui("settings_footer")
+            .replace("{theme}", state.current_theme)
+            .replace("{language}", state.current_language_display);
+        let dialog_block = Block::default()
+            .title(state.localization.ui("settings_title"))
+            .title_bottom(Line::from(footer).style(Style::default().fg(t.text)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        // Settings options
+        let settings_options = settings_option_labels(state);
+
+        // The selected option is filled with the primary color
+        let items: Vec<ListItem> = settings_options
+            .into_iter()
+            .enumerate()
+            .map(|(index, option_text)| {
+                if list_state.selected() == Some(index) {
+                    ListItem::new(Line::from(
+                        styled_span!(raw: option_text, fg: t.text, bg: t.primary),
+                    ))
+                } else {
+                    ListItem::new(option_text)
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .style(Style::default().fg(t.text))
+            .highlight_style(Style::default().bold());
+        frame.render_stateful_widget(list, inner_area, list_state);
+
+        // Render instructions at the bottom, with the digit shortcut hint on a second line
+        let instruction_rect = Rect::new(
+            dialog_rect.x + 1,
+            dialog_rect.y + dialog_rect.height,
+            dialog_rect.width - 2,
+            2,
+        )
+        .intersection(area);
+        let instruction = Paragraph::new(vec![
+            Line::from(state.localization.msg("settings_instruction")),
+            Line::from(settings_digits_instruction(state)),
+        ])
+        .style(Style::default().fg(t.text));
+        frame.render_widget(instruction, instruction_rect);
+    }
+
+    /// Renders the language selection dialog
+    ///
+    /// - `state`: The app state to render
+    /// - `list_state`: The scroll/selection state of the language list
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    ///
+    /// This dialog displays a list of languages, with a search box and a list of languages.
+    /// Returns the number of visible rows in the list, used to page through it.
+    fn render_language_dialog(
+        state: &AppState,
+        list_state: &mut ListState,
+        frame: &mut Frame,
+        t: &Theme,
+    ) -> usize {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), the list grows with the terminal
+        let dialog_rect = dialog_rect(area, percent_of(area.height, 60).min(15));
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let dialog_block = Block::default()
+            .title(state.localization.ui("language_dialog_title"))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        // Split into search box and list
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Search box
+                Constraint::Min(0),    // Language list
+            ])
+            .split(inner_area);
+
+        // Render search box
+        let search_text = if state.language_search.is_empty() {
+            state
+                .localization
+                .ui("language_search_placeholder")
+                .to_string()
+        } else {
+            format!(
+                "{}{}",
+                state.language_search,
+                state.localization.ui("input_cursor")
+            )
+        };
+
+        let search_box = Paragraph::new(search_text)
+            .style(Style::default().fg(t.primary))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(t.text)),
+            );
+        frame.render_widget(search_box, chunks[0]);
+
+        // Render language list
+        if state.filtered_languages.is_empty() {
+            let no_results = Paragraph::new(state.localization.ui("no_languages_found"))
+                .style(Style::default().fg(t.text))
+                .alignment(Alignment::Center);
+            frame.render_widget(no_results, chunks[1]);
+        } else {
+            let items: Vec<ListItem> = state
+                .filtered_languages
+                .iter()
+                .enumerate()
+                .map(|(i, (code, display))| {
+                    let style = if i == state.language_selected {
+                        Style::default().fg(t.primary).bold()
+                    } else {
+                        Style::default().fg(t.text)
+                    };
+                    ListItem::new(language_label(state, code, display)).style(style)
+                })
+                .collect();
+
+            let list = List::new(items);
+            list_state.select(Some(state.language_selected));
+            frame.render_stateful_widget(list, chunks[1], list_state);
+        }
+
+        // Render instruction at the bottom
+        let instruction_rect = Rect::new(
+            dialog_rect.x + 1,
+            dialog_rect.y + dialog_rect.height,
+            dialog_rect.width - 2,
+            1,
+        );
+        let instruction = Paragraph::new(state.localization.msg("language_instruction"))
+            .style(Style::default().fg(t.text));
+        frame.render_widget(instruction, instruction_rect);
+
+        chunks[1].height as usize
+    }
+
+    /// Renders the new app dialog
+    ///
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    ///
+    /// This dialog is triggered when no Rext app is found in the current directory.
+    /// It allows the user to create a new Rext app.
+    /// TODO - after creating the app, hide the buttons for clarity.
+    fn render_new_app_dialog(state: &AppState, frame: &mut Frame, t: &Theme) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), tall enough for its content
+        let dialog_rect = dialog_rect(area, 14);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        // A double border and filled title set the mandatory dialog apart from optional ones
+        let dialog_block = Block::default()
+            .title(
+                Line::from(state.localization.ui("new_app_dialog_title"))
+                    .style(Style::default().fg(t.background).bg(t.primary))
+                    .bold()
+                    .centered(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        // Buttons don't fit side by side on very narrow terminals, stack them instead
+        let stack_buttons = inner_area.width < NEW_APP_BUTTONS_MIN_WIDTH;
+
+        // Layout for dialog content
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(1),                                 // App name input
+                Constraint::Length(1),                                 // App name error (if any)
                 Constraint::Length(2), // Top spacing + no app detected message
                 Constraint::Length(1), // Question message
-                Constraint::Length(2), // Spacing
-                Constraint::Length(3), // Buttons
+                Constraint::Length(if stack_buttons { 0 } else { 2 }), // Spacing
+                Constraint::Length(if stack_buttons { 6 } else { 3 }), // Buttons
                 Constraint::Length(1), // Result message (if any)
                 Constraint::Min(0),    // Bottom spacing
             ])
             .split(inner_area);
 
+        // Render the app name input, with a cursor while it's focused
+        let name_color = if state.new_app_name_focused {
+            t.primary
+        } else {
+            t.text
+        };
+        let mut name_spans = vec![
+            styled_span!(state.localization, ui, "new_app_name_prompt", t.text),
+            ratatui::text::Span::styled(
+                format!(" {}", state.new_app_name_input),
+                Style::default().fg(name_color),
+            ),
+        ];
+        if state.new_app_name_focused {
+            name_spans.push(styled_span!(
+                state.localization,
+                ui,
+                "input_cursor",
+                t.primary
+            ));
+        }
+        let name_input = Paragraph::new(Line::from(name_spans)).alignment(Alignment::Center);
+        frame.render_widget(name_input, chunks[0]);
+
+        // Render the inline validation error for the app name
+        if let Some(error_key) = app_name_error(state.new_app_name_input) {
+            let name_error = Paragraph::new(state.localization.ui(error_key))
+                .style(Style::default().fg(Color::Red))
+                .alignment(Alignment::Center);
+            frame.render_widget(name_error, chunks[1]);
+        }
+
         // Render "No rext app detected!" message
-        let no_app_message = Paragraph::new(self.localization.ui("new_app_no_app_detected"))
+        let no_app_message = Paragraph::new(state.localization.ui("new_app_no_app_detected"))
             .style(Style::default().fg(t.text))
             .alignment(Alignment::Center);
-        frame.render_widget(no_app_message, chunks[0]);
+        frame.render_widget(no_app_message, chunks[2]);
 
         // Render "Would you like to create a new Rext app?" question
-        let question_message = Paragraph::new(self.localization.ui("new_app_dialog_prompt"))
+        let question_message = Paragraph::new(state.localization.ui("new_app_dialog_prompt"))
             .style(Style::default().fg(t.text))
             .alignment(Alignment::Center);
-        frame.render_widget(question_message, chunks[1]);
+        frame.render_widget(question_message, chunks[3]);
 
         // Render buttons - using fixed width and centering
-        let button_area = chunks[3];
+        let button_area = chunks[5];
 
-        // Create a horizontal layout with flexible spacing to center the buttons
-        let button_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Min(0),     // Flexible left spacing
-                Constraint::Length(15), // Create button (fixed 10 chars)
-                Constraint::Length(4),  // Gap between buttons
-                Constraint::Length(15), // Cancel button (fixed 10 chars)
-                Constraint::Min(0),     // Flexible right spacing
-            ])
-            .split(button_area);
+        // Create a horizontal layout with flexible spacing to center the buttons,
+        // or a vertical one with full-width buttons on separate rows if they don't fit
+        let button_layout = if stack_buttons {
+            Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(0), // No top spacing
+                    Constraint::Length(3), // Create button (full width)
+                    Constraint::Length(0), // No gap between buttons
+                    Constraint::Length(3), // Cancel button (full width)
+                    Constraint::Min(0),    // Remaining space
+                ])
+                .split(button_area)
+        } else {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Min(0),     // Flexible left spacing
+                    Constraint::Length(15), // Create button (fixed 10 chars)
+                    Constraint::Length(4),  // Gap between buttons
+                    Constraint::Length(15), // Cancel button (fixed 10 chars)
+                    Constraint::Min(0),     // Flexible right spacing
+                ])
+                .split(button_area)
+        };
 
         // How do buttons work? Well
         // There is the style, the paragraph of text, and the block.
@@ -689,20 +2488,21 @@ impl App {
         //
 
         // Create button style
-        let create_style = if self.new_app_button_selected == 0 {
+        let buttons_focused = !state.new_app_name_focused;
+        let create_style = if buttons_focused && state.focused_button == 0 {
             Style::default().fg(t.background).bg(t.primary)
         } else {
             Style::default().fg(t.primary).bg(t.background)
         };
 
         // create block border style
-        let create_block_style = if self.new_app_button_selected == 0 {
+        let create_block_style = if buttons_focused && state.focused_button == 0 {
             Style::default().fg(t.background)
         } else {
             Style::default().fg(t.primary)
         };
 
-        let create_button = Paragraph::new(self.localization.ui("new_app_create_button"))
+        let create_button = Paragraph::new(state.localization.ui("new_app_create_button"))
             .style(create_style)
             .alignment(Alignment::Center)
             .block(
@@ -713,20 +2513,20 @@ impl App {
         frame.render_widget(create_button, button_layout[1]);
 
         // Cancel button style
-        let cancel_style = if self.new_app_button_selected == 1 {
+        let cancel_style = if buttons_focused && state.focused_button == 1 {
             Style::default().fg(t.background).bg(t.primary)
         } else {
             Style::default().fg(t.primary).bg(t.background)
         };
 
         // cancel block border style
-        let cancel_block_style = if self.new_app_button_selected == 1 {
+        let cancel_block_style = if buttons_focused && state.focused_button == 1 {
             Style::default().fg(t.background)
         } else {
             Style::default().fg(t.primary)
         };
 
-        let cancel_button = Paragraph::new(self.localization.ui("new_app_cancel_button"))
+        let cancel_button = Paragraph::new(state.localization.ui("new_app_cancel_button"))
             .style(cancel_style)
             .alignment(Alignment::Center)
             .block(
@@ -737,16 +2537,16 @@ impl App {
         frame.render_widget(cancel_button, button_layout[3]);
 
         // Render result message if present
-        if let Some(ref message) = self.new_app_message {
+        if let Some(message) = state.new_app_message {
             let message_style = if message.contains("problem") {
                 Style::default().fg(Color::Red)
             } else {
                 Style::default().fg(Color::Green)
             };
-            let result_message = Paragraph::new(message.clone())
+            let result_message = Paragraph::new(message)
                 .style(message_style)
                 .alignment(Alignment::Center);
-            frame.render_widget(result_message, chunks[4]);
+            frame.render_widget(result_message, chunks[6]);
         }
 
         // Render instruction at the bottom
@@ -756,213 +2556,1065 @@ impl App {
             dialog_rect.width - 2,
             1,
         );
-        let instruction = Paragraph::new(self.localization.msg("new_app_instruction"))
+        let instruction = Paragraph::new(state.localization.msg("new_app_instruction"))
+            .style(Style::default().fg(t.text));
+        frame.render_widget(instruction, instruction_rect);
+    }
+
+    /// Renders the recent themes dialog
+    ///
+    /// - `state`: The app state to render
+    /// - `list_state`: The scroll/selection state of the recent themes list
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    fn render_recent_themes_dialog(
+        state: &AppState,
+        list_state: &mut ListState,
+        frame: &mut Frame,
+        t: &Theme,
+    ) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), one row per theme
+        let dialog_rect = dialog_rect(area, MAX_RECENT_THEMES as u16 + 2);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let dialog_block = Block::default()
+            .title(state.localization.ui("recent_themes_title"))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        if state.recently_used_themes.is_empty() {
+            let no_themes = Paragraph::new(state.localization.ui("no_recent_themes"))
+                .style(Style::default().fg(t.text))
+                .alignment(Alignment::Center);
+            frame.render_widget(no_themes, inner_area);
+        } else {
+            let items: Vec<ListItem> = state
+                .recently_used_themes
+                .iter()
+                .map(|theme| ListItem::new(theme.as_str()))
+                .collect();
+
+            let list = List::new(items)
+                .style(Style::default().fg(t.text))
+                .highlight_style(Style::default().fg(t.primary).bold());
+            frame.render_stateful_widget(list, inner_area, list_state);
+        }
+
+        // Render instruction at the bottom
+        let instruction_rect = Rect::new(
+            dialog_rect.x + 1,
+            dialog_rect.y + dialog_rect.height,
+            dialog_rect.width.saturating_sub(2),
+            1,
+        )
+        .intersection(area);
+        let instruction = Paragraph::new(state.localization.msg("recent_themes_instruction"))
             .style(Style::default().fg(t.text));
         frame.render_widget(instruction, instruction_rect);
     }
 
+    /// Renders the error dialog
+    ///
+    /// - `message`: The error message to show
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    fn render_error_dialog(message: &str, state: &AppState, frame: &mut Frame, t: &Theme) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered)
+        let dialog_rect = dialog_rect(area, 9);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let dialog_block = Block::default()
+            .title(Line::from(state.localization.ui("error_dialog_title")).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Error message
+                Constraint::Length(3), // OK button
+            ])
+            .split(inner_area);
+
+        // Render the error message
+        let error_message = Paragraph::new(message)
+            .style(Style::default().fg(t.text))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(error_message, chunks[0]);
+
+        // Render the OK button, always focused since it's the only one
+        let button_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(0),     // Flexible left spacing
+                Constraint::Length(15), // OK button
+                Constraint::Min(0),     // Flexible right spacing
+            ])
+            .split(chunks[1]);
+        let ok_button = Paragraph::new(state.localization.ui("error_dialog_ok_button"))
+            .style(Style::default().fg(t.background).bg(t.primary))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(t.background)),
+            );
+        frame.render_widget(ok_button, button_layout[1]);
+    }
+
     /// Reads the crossterm events and updates the state of [`App`].
     fn handle_crossterm_events(&mut self) -> Result<(), RextTuiError> {
-        match event::read()? {
-            Event::Key(key) if key.kind == KeyEventKind::Press => self.on_key_event(key),
+        let event = match &mut self.event_source {
+            Some(source) => read_event_with_retry(source)?,
+            None => read_event_with_retry(event::read)?,
+        };
+        self.handle_event(event);
+        Ok(())
+    }
+
+    /// Updates the state of [`App`] for a single crossterm event.
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                self.on_key_event(key);
+            }
+            Event::Paste(text) => self.on_paste(&text),
             Event::Mouse(_) => {}
-            Event::Resize(_, _) => {}
+            // The app may have been changed in another window, check it again on the next tick
+            Event::FocusGained => self.rext_app_cache_dirty = true,
+            // Resume drawing once the terminal is big enough again
+            Event::Resize(width, height) if width >= MIN_WIDTH && height >= MIN_HEIGHT => {
+                self.terminal_too_small = false;
+            }
             _ => {}
         }
-        Ok(())
     }
 
-    /// Handles the key events and updates the state of [`App`].
-    pub fn on_key_event(&mut self, key: KeyEvent) {
-        match &self.current_dialog {
-            DialogType::ApiEndpoint => {
-                self.handle_api_endpoint_dialog_events(key);
-            }
-            DialogType::Settings => {
-                self.handle_settings_dialog_events(key);
-            }
-            DialogType::Language => {
-                self.handle_language_dialog_events(key);
+    /// Handles the key events and updates the state of [`App`].
+    ///
+    /// Returns `true` if the key triggered an action, `false` if no binding matched it,
+    /// so embedding code can pass unhandled keys on.
+    pub fn on_key_event(&mut self, key: KeyEvent) -> bool {
+        log::trace!(
+            "key_event: dialog={:?} modifiers={:?} code={:?}",
+            self.current_dialog,
+            key.modifiers,
+            key.code
+        );
+        // Plain characters typed into a text input never start a key sequence
+        let typed = matches!(key.code, KeyCode::Char(_))
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        if !(typed && self.text_input_focused()) {
+            match self.feed_key_sequence(key) {
+                KeySequenceStep::Complete(action) => {
+                    log::debug!("action: {}", action);
+                    if action == "goto_top" {
+                        self.goto_top();
+                    }
+                    return true;
+                }
+                KeySequenceStep::Pending => return true,
+                KeySequenceStep::Unmatched => {}
+            }
+        }
+
+        match &self.current_dialog {
+            DialogType::ApiEndpoint => self.handle_api_endpoint_dialog_events(key),
+            DialogType::Settings => self.handle_settings_dialog_events(key),
+            DialogType::Language => self.handle_language_dialog_events(key),
+            DialogType::NewApp => self.handle_new_app_dialog_events(key),
+            DialogType::RecentThemes => self.handle_recent_themes_dialog_events(key),
+            DialogType::SeaOrmConfig => self.handle_sea_orm_config_dialog_events(key),
+            DialogType::TelemetryConsent => self.handle_telemetry_consent_dialog_events(key),
+            DialogType::ConfirmDestroy { .. } => self.handle_confirm_destroy_dialog_events(key),
+            DialogType::Error { .. } => self.handle_error_dialog_events(key),
+            DialogType::None => self.handle_main_app_events(key),
+        }
+    }
+
+    /// Handles events for the API endpoint dialog
+    fn handle_api_endpoint_dialog_events(&mut self, key: KeyEvent) -> bool {
+        if self
+            .localization
+            .matches_key("enter", key.modifiers, key.code)
+        {
+            if self.ask_to_overwrite_endpoint() {
+                return true;
+            }
+
+            // Close dialog and process the API endpoint name
+            let api_endpoint_name = self.api_endpoint_input.clone();
+            self.close_dialog();
+            self.create_api_endpoint(api_endpoint_name);
+        } else if self
+            .localization
+            .matches_key("submit_and_continue", key.modifiers, key.code)
+        {
+            if self.api_endpoint_input.is_empty() || self.ask_to_overwrite_endpoint() {
+                return true;
+            }
+
+            // Create the endpoint and keep the dialog open for the next one
+            let api_endpoint_name = std::mem::take(&mut self.api_endpoint_input);
+            self.create_api_endpoint(api_endpoint_name);
+            self.api_endpoint_created_count += 1;
+            self.history_selected = None;
+            self.api_endpoint_overwrite_confirmed = false;
+        } else if self
+            .localization
+            .matches_key("escape", key.modifiers, key.code)
+        {
+            self.close_dialog();
+        } else if self
+            .localization
+            .matches_key("autocomplete", key.modifiers, key.code)
+        {
+            self.cycle_endpoint_suggestion();
+        } else if self
+            .localization
+            .matches_key("select_all", key.modifiers, key.code)
+        {
+            // The cursor always sits at the end of the input, there's no selection to extend
+        } else if self
+            .localization
+            .matches_key("clear_input", key.modifiers, key.code)
+        {
+            self.api_endpoint_input.clear();
+            self.history_selected = None;
+            self.api_endpoint_overwrite_confirmed = false;
+        } else if self
+            .localization
+            .matches_key("delete_word", key.modifiers, key.code)
+        {
+            pop_word(&mut self.api_endpoint_input);
+            self.history_selected = None;
+            self.api_endpoint_overwrite_confirmed = false;
+        } else if self
+            .localization
+            .matches_key("backspace", key.modifiers, key.code)
+        {
+            self.api_endpoint_input.pop();
+            self.history_selected = None;
+            self.api_endpoint_overwrite_confirmed = false;
+        } else if let KeyCode::Char(c) = key.code {
+            self.api_endpoint_input.push(c);
+            self.history_selected = None;
+            self.api_endpoint_overwrite_confirmed = false;
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Inserts pasted text into the text input of the current dialog
+    ///
+    /// All inputs are single-line, so only the first line of a multi-line paste is used.
+    pub fn on_paste(&mut self, text: &str) {
+        let line = text.lines().next().unwrap_or_default();
+        match self.current_dialog {
+            DialogType::ApiEndpoint => {
+                self.api_endpoint_input.push_str(line);
+                self.history_selected = None;
+                self.api_endpoint_overwrite_confirmed = false;
+            }
+            DialogType::Language => {
+                self.language_search.push_str(line);
+                self.filter_languages();
+            }
+            DialogType::NewApp if self.new_app_name_focused => {
+                self.new_app_name_input.push_str(line);
+            }
+            DialogType::SeaOrmConfig => {
+                self.focused_sea_orm_input().push_str(line);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles events for the settings dialog
+    fn handle_settings_dialog_events(&mut self, key: KeyEvent) -> bool {
+        let option_count = SettingsOption::all_variants().len();
+        if self
+            .localization
+            .matches_key("escape", key.modifiers, key.code)
+        {
+            self.close_dialog();
+        } else if self.localization.matches_key("up", key.modifiers, key.code) {
+            if self.settings_selected > 0 {
+                self.select_setting(self.settings_selected - 1);
+            } else {
+                self.select_setting(option_count - 1); // Wrap to bottom
+            }
+        } else if self
+            .localization
+            .matches_key("down", key.modifiers, key.code)
+        {
+            self.select_setting((self.settings_selected + 1) % option_count);
+        } else if self
+            .localization
+            .matches_key("home", key.modifiers, key.code)
+        {
+            self.select_setting(0);
+        } else if self
+            .localization
+            .matches_key("end", key.modifiers, key.code)
+        {
+            self.select_setting(option_count - 1);
+        } else if self.settings_selected == 0
+            && self
+                .localization
+                .matches_key("theme_cycle_reverse", key.modifiers, key.code)
+        {
+            // Theme option, cycling backwards
+            self.cycle_theme(true);
+        } else if self
+            .localization
+            .matches_key("enter", key.modifiers, key.code)
+        {
+            self.activate_setting();
+        } else if let Some(digit) = match key.code {
+            KeyCode::Char(c) => c.to_digit(10).map(|digit| digit as usize),
+            _ => None,
+        }
+        .filter(|digit| (1..=option_count).contains(digit))
+        {
+            // Digits select and activate the matching option directly
+            self.select_setting(digit - 1);
+            self.activate_setting();
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Runs the action of the selected settings option
+    fn activate_setting(&mut self) {
+        let Some(option) = SettingsOption::all_variants().get(self.settings_selected) else {
+            return;
+        };
+        match option {
+            SettingsOption::Theme => self.cycle_theme(false),
+            SettingsOption::Language => self.open_language_dialog(),
+            SettingsOption::LanguageCodes => {
+                self.show_language_codes = !self.show_language_codes;
+            }
+            SettingsOption::Destroy => {
+                self.focused_button = 1; // Default to No, destroying can't be undone
+                self.set_dialog(DialogType::ConfirmDestroy { undo: false });
+            }
+            SettingsOption::Close => self.close_dialog(),
+            SettingsOption::LanguageStickySearch => {
+                self.sticky_language_search = !self.sticky_language_search;
+                self.save_settings();
+            }
+        }
+    }
+
+    /// Destroys the Rext app in the workspace, the result is shown as the new app message
+    fn destroy_app(&mut self) {
+        match self
+            .workspace_dir()
+            .and_then(|dir| self.rext_core.destroy_rext_app(&dir))
+        {
+            Ok(_) => {
+                self.record_telemetry("destroy_app");
+                self.record_operation("destroy_app", true, self.workspace.dir_name.clone());
+                self.new_app_message = Some(
+                    self.localization
+                        .msg("destroy_app_success")
+                        .replace("{dir_name}", &self.workspace.dir_name),
+                );
+            }
+            Err(e) => {
+                self.record_operation("destroy_app", false, e.to_string());
+                self.new_app_message = Some(
+                    self.localization
+                        .msg("destroy_app_error")
+                        .replace("{error}", &e.to_string()),
+                );
+            }
+        }
+    }
+
+    /// Selects a settings option, keeping the settings list state in sync
+    fn select_setting(&mut self, index: usize) {
+        self.settings_selected = index;
+        self.settings_list_state.select(Some(index));
+    }
+
+    /// Handles events for the language dialog
+    fn handle_language_dialog_events(&mut self, key: KeyEvent) -> bool {
+        if self
+            .localization
+            .matches_key("escape", key.modifiers, key.code)
+        {
+            self.close_dialog();
+        } else if self.localization.matches_key("up", key.modifiers, key.code) {
+            if !self.filtered_languages.is_empty() && self.language_selected > 0 {
+                self.language_selected -= 1;
+            } else if !self.filtered_languages.is_empty() {
+                self.language_selected = self.filtered_languages.len() - 1;
             }
-            DialogType::NewApp => {
-                self.handle_new_app_dialog_events(key);
+        } else if self
+            .localization
+            .matches_key("down", key.modifiers, key.code)
+        {
+            if !self.filtered_languages.is_empty() {
+                self.language_selected =
+                    (self.language_selected + 1) % self.filtered_languages.len();
             }
-            DialogType::None => {
-                self.handle_main_app_events(key);
+        } else if self
+            .localization
+            .matches_key("page_up", key.modifiers, key.code)
+        {
+            self.language_selected = self
+                .language_selected
+                .saturating_sub(self.language_list_page_size.max(1));
+        } else if self
+            .localization
+            .matches_key("page_down", key.modifiers, key.code)
+        {
+            self.language_selected = (self.language_selected + self.language_list_page_size.max(1))
+                .min(self.filtered_languages.len().saturating_sub(1));
+        } else if self
+            .localization
+            .matches_key("home", key.modifiers, key.code)
+        {
+            self.language_selected = 0;
+            self.language_list_state.select(Some(0));
+        } else if self
+            .localization
+            .matches_key("end", key.modifiers, key.code)
+        {
+            self.language_selected = self.filtered_languages.len().saturating_sub(1);
+            self.language_list_state
+                .select(Some(self.language_selected));
+        } else if self
+            .localization
+            .matches_key("enter", key.modifiers, key.code)
+        {
+            if !self.filtered_languages.is_empty() {
+                let selected_language = self.filtered_languages[self.language_selected].0.clone();
+                self.select_language(selected_language);
             }
+        } else if self
+            .localization
+            .matches_key("backspace", key.modifiers, key.code)
+        {
+            self.language_search.pop();
+            self.filter_languages();
+        } else if self
+            .localization
+            .matches_key("select_all", key.modifiers, key.code)
+        {
+            // The cursor always sits at the end of the search, there's no selection to extend
+        } else if self
+            .localization
+            .matches_key("clear_input", key.modifiers, key.code)
+        {
+            self.language_search.clear();
+            self.filter_languages();
+        } else if let KeyCode::Char(c) = key.code {
+            self.language_search.push(c);
+            self.filter_languages();
+        } else {
+            return false;
         }
+        true
     }
 
-    /// Handles events for the API endpoint dialog
-    fn handle_api_endpoint_dialog_events(&mut self, key: KeyEvent) {
-        if self
+    /// Handles events for the new app dialog
+    fn handle_new_app_dialog_events(&mut self, key: KeyEvent) -> bool {
+        if self.new_app_name_focused {
+            return self.handle_new_app_name_input_events(key);
+        } else if self
+            .localization
+            .matches_key("autocomplete", key.modifiers, key.code)
+        {
+            // Move focus back to the name input
+            self.new_app_name_focused = true;
+        } else if self
+            .localization
+            .matches_key("left", key.modifiers, key.code)
+        {
+            // Navigate to Create button (0)
+            self.focused_button = 0;
+        } else if self
+            .localization
+            .matches_key("right", key.modifiers, key.code)
+        {
+            // Navigate to Cancel button (1)
+            self.focused_button = 1;
+        } else if self
             .localization
             .matches_key("enter", key.modifiers, key.code)
         {
-            // Close dialog and process the API endpoint name
-            let api_endpoint_name = self.api_endpoint_input.clone();
+            // Handle button action based on selection
+            if self.focused_button == 0 {
+                // Create button - scaffold new app
+                self.handle_new_app_creation();
+            } else {
+                // Cancel button - quit application
+                self.quit();
+            }
+        } else if self
+            .localization
+            .matches_key("escape", key.modifiers, key.code)
+        {
             self.close_dialog();
-            self.handle_api_endpoint_creation(api_endpoint_name);
+        } else if self
+            .localization
+            .matches_key("quit", key.modifiers, key.code)
+            || self
+                .localization
+                .matches_key("quit_combo", key.modifiers, key.code)
+        {
+            // Include option to quit from new app dialog
+            self.quit();
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Handles events for the new app dialog while the app name input is focused
+    fn handle_new_app_name_input_events(&mut self, key: KeyEvent) -> bool {
+        if self
+            .localization
+            .matches_key("autocomplete", key.modifiers, key.code)
+        {
+            // Only move on to the buttons with a valid name, the inline error explains why not
+            if app_name_error(&self.new_app_name_input).is_none() {
+                self.new_app_name_focused = false;
+                self.focused_button = 0;
+            }
         } else if self
             .localization
             .matches_key("escape", key.modifiers, key.code)
         {
             self.close_dialog();
+        } else if self
+            .localization
+            .matches_key("quit_combo", key.modifiers, key.code)
+        {
+            self.quit();
         } else if self
             .localization
             .matches_key("backspace", key.modifiers, key.code)
         {
-            self.api_endpoint_input.pop();
+            self.new_app_name_input.pop();
         } else if let KeyCode::Char(c) = key.code {
-            self.api_endpoint_input.push(c);
+            if key.modifiers.contains(KeyModifiers::CONTROL) {
+                return false;
+            }
+            self.new_app_name_input.push(c);
+        } else {
+            return false;
         }
+        true
     }
 
-    /// Handles events for the settings dialog
-    fn handle_settings_dialog_events(&mut self, key: KeyEvent) {
+    /// Handles events for the recent themes dialog
+    fn handle_recent_themes_dialog_events(&mut self, key: KeyEvent) -> bool {
+        let count = self.recently_used_themes.len();
+        let selected = self.recent_themes_list_state.selected();
+
         if self
             .localization
             .matches_key("escape", key.modifiers, key.code)
         {
             self.close_dialog();
         } else if self.localization.matches_key("up", key.modifiers, key.code) {
-            if self.settings_selected > 0 {
-                self.settings_selected -= 1;
-            } else {
-                self.settings_selected = 3; // Wrap to bottom (Close option)
+            if let Some(index) = selected {
+                self.recent_themes_list_state
+                    .select(Some((index + count - 1) % count));
             }
         } else if self
             .localization
             .matches_key("down", key.modifiers, key.code)
         {
-            self.settings_selected = (self.settings_selected + 1) % 4;
+            if let Some(index) = selected {
+                self.recent_themes_list_state
+                    .select(Some((index + 1) % count));
+            }
         } else if self
             .localization
             .matches_key("enter", key.modifiers, key.code)
         {
-            match self.settings_selected {
-                0 => {
-                    // Theme option
-                    self.cycle_theme();
-                }
-                1 => {
-                    // Language option
-                    self.open_language_dialog();
-                }
-                2 => {
-                    // Destroy option
-                    match rext_core::destroy_rext_app() {
-                        Ok(_) => {
-                            self.new_app_message = Some(
-                                self.localization
-                                    .msg("destroy_app_success")
-                                    .replace("{dir_name}", &self.current_dir_name),
-                            );
-                        }
-                        Err(e) => {
-                            self.new_app_message = Some(
-                                self.localization
-                                    .msg("destroy_app_error")
-                                    .replace("{error}", &e.to_string()),
-                            );
-                        }
-                    }
-                }
-                3 => {
-                    // Close option
-                    self.close_dialog();
-                }
-                _ => {}
+            if let Some(theme) = selected.and_then(|i| self.recently_used_themes.get(i)) {
+                self.select_theme(theme.clone());
             }
+            self.close_dialog();
+        } else {
+            return false;
         }
+        true
     }
 
-    /// Handles events for the language dialog
-    fn handle_language_dialog_events(&mut self, key: KeyEvent) {
+    /// Renders the telemetry consent dialog in the center of the screen
+    ///
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    fn render_telemetry_consent_dialog(state: &AppState, frame: &mut Frame, t: &Theme) {
+        let l = state.localization;
+        Self::render_yes_no_dialog(
+            state,
+            frame,
+            t,
+            &l.ui("telemetry_consent_title"),
+            &l.ui("telemetry_consent_prompt"),
+            [
+                &l.ui("telemetry_consent_yes"),
+                &l.ui("telemetry_consent_no"),
+            ],
+        );
+    }
+
+    /// Renders the destroy app confirmation dialog in the center of the screen
+    ///
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    fn render_confirm_destroy_dialog(state: &AppState, frame: &mut Frame, t: &Theme) {
+        let l = state.localization;
+        Self::render_yes_no_dialog(
+            state,
+            frame,
+            t,
+            &l.ui("destroy_app_confirm_title"),
+            &l.ui("destroy_app_confirm_prompt")
+                .replace("{dir_name}", state.workspace_dir_name),
+            [
+                &l.ui("destroy_app_confirm_yes"),
+                &l.ui("destroy_app_confirm_no"),
+            ],
+        );
+    }
+
+    /// Renders a dialog with a question and yes and no buttons, the focused button highlighted
+    ///
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    /// - `title`: The dialog title
+    /// - `prompt`: The question
+    /// - `buttons`: The yes and no button labels
+    fn render_yes_no_dialog(
+        state: &AppState,
+        frame: &mut Frame,
+        t: &Theme,
+        title: &str,
+        prompt: &str,
+        buttons: [&str; 2],
+    ) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), tall enough for its content
+        let dialog_rect = dialog_rect(area, 9);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let dialog_block = Block::default()
+            .title(Line::from(title).centered())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Question
+                Constraint::Length(3), // Yes and No buttons
+            ])
+            .split(inner_area);
+
+        let question = Paragraph::new(prompt)
+            .style(Style::default().fg(t.text))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(question, chunks[0]);
+
+        let button_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(0),     // Flexible left spacing
+                Constraint::Length(15), // Yes button
+                Constraint::Length(4),  // Gap between buttons
+                Constraint::Length(15), // No button
+                Constraint::Min(0),     // Flexible right spacing
+            ])
+            .split(chunks[1]);
+        for (index, (label, rect)) in buttons
+            .into_iter()
+            .zip([button_layout[1], button_layout[3]])
+            .enumerate()
+        {
+            let (style, border_style) = if state.focused_button == index {
+                (
+                    Style::default().fg(t.background).bg(t.primary),
+                    Style::default().fg(t.background),
+                )
+            } else {
+                (
+                    Style::default().fg(t.primary).bg(t.background),
+                    Style::default().fg(t.primary),
+                )
+            };
+            let button = Paragraph::new(label)
+                .style(style)
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(border_style),
+                );
+            frame.render_widget(button, rect);
+        }
+    }
+
+    /// Handles events for the destroy app confirmation dialog, Esc cancels
+    ///
+    /// Either way it goes back to the settings dialog, or the main screen when undoing.
+    fn handle_confirm_destroy_dialog_events(&mut self, key: KeyEvent) -> bool {
+        let undo = matches!(
+            self.current_dialog,
+            DialogType::ConfirmDestroy { undo: true }
+        );
+        let return_to = if undo {
+            DialogType::None
+        } else {
+            DialogType::Settings
+        };
+        if self
+            .localization
+            .matches_key("left", key.modifiers, key.code)
+        {
+            self.focused_button = 0;
+        } else if self
+            .localization
+            .matches_key("right", key.modifiers, key.code)
+        {
+            self.focused_button = 1;
+        } else if self
+            .localization
+            .matches_key("enter", key.modifiers, key.code)
+        {
+            self.set_dialog(return_to);
+            if self.focused_button == 0 && undo {
+                self.run_undo();
+            } else if self.focused_button == 0 {
+                self.destroy_app();
+            }
+        } else if self
+            .localization
+            .matches_key("escape", key.modifiers, key.code)
+        {
+            self.set_dialog(return_to);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Renders the SeaORM config dialog in the center of the screen
+    ///
+    /// - `state`: The app state to render
+    /// - `frame`: The frame to render the dialog on
+    /// - `t`: The theme to use for the dialog
+    fn render_sea_orm_config_dialog(state: &AppState, frame: &mut Frame, t: &Theme) {
+        let area = frame.area();
+
+        // Calculate dialog size and position (centered), tall enough for its content
+        let dialog_rect = dialog_rect(area, 8);
+
+        // Clear the area behind the dialog
+        frame.render_widget(Clear, dialog_rect);
+
+        // Create dialog block with border
+        let dialog_block = Block::default()
+            .title(state.localization.ui("sea_orm_config_title"))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.primary))
+            .style(t.base_style());
+
+        let inner_area = dialog_block.inner(dialog_rect);
+        frame.render_widget(dialog_block, dialog_rect);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Database URL label
+                Constraint::Length(1), // Database URL input
+                Constraint::Length(1), // Output directory label
+                Constraint::Length(1), // Output directory input
+                Constraint::Min(1),    // Instructions
+            ])
+            .split(inner_area);
+
+        // Render both inputs, the focused one highlighted with a cursor
+        let inputs = [
+            (
+                "sea_orm_database_url_prompt",
+                state.sea_orm_database_url_input,
+                !state.sea_orm_output_dir_focused,
+            ),
+            (
+                "sea_orm_output_dir_prompt",
+                state.sea_orm_output_dir_input,
+                state.sea_orm_output_dir_focused,
+            ),
+        ];
+        for (i, (prompt, input, focused)) in inputs.into_iter().enumerate() {
+            let label =
+                Paragraph::new(state.localization.ui(prompt)).style(Style::default().fg(t.text));
+            frame.render_widget(label, chunks[i * 2]);
+
+            let (input_text, color) = if focused {
+                (
+                    format!("{}{}", input, state.localization.ui("input_cursor")),
+                    t.primary,
+                )
+            } else {
+                (input.to_string(), t.text)
+            };
+            let input = Paragraph::new(input_text).style(Style::default().fg(color));
+            frame.render_widget(input, chunks[i * 2 + 1]);
+        }
+
+        let instructions = Paragraph::new(state.localization.msg("sea_orm_config_instruction"))
+            .style(Style::default().fg(t.text))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(instructions, chunks[4]);
+    }
+
+    /// Handles events for the SeaORM config dialog
+    fn handle_sea_orm_config_dialog_events(&mut self, key: KeyEvent) -> bool {
         if self
             .localization
             .matches_key("escape", key.modifiers, key.code)
         {
             self.close_dialog();
-        } else if self.localization.matches_key("up", key.modifiers, key.code) {
-            if !self.filtered_languages.is_empty() && self.language_selected > 0 {
-                self.language_selected -= 1;
-            } else if !self.filtered_languages.is_empty() {
-                self.language_selected = self.filtered_languages.len() - 1;
-            }
         } else if self
             .localization
-            .matches_key("down", key.modifiers, key.code)
+            .matches_key("autocomplete", key.modifiers, key.code)
         {
-            if !self.filtered_languages.is_empty() {
-                self.language_selected =
-                    (self.language_selected + 1) % self.filtered_languages.len();
-            }
+            self.sea_orm_output_dir_focused = !self.sea_orm_output_dir_focused;
         } else if self
             .localization
             .matches_key("enter", key.modifiers, key.code)
         {
-            if !self.filtered_languages.is_empty() {
-                let selected_language = self.filtered_languages[self.language_selected].0.clone();
-                self.select_language(selected_language);
-            }
+            self.save_sea_orm_config_and_generate();
+        } else if self
+            .localization
+            .matches_key("clear_input", key.modifiers, key.code)
+        {
+            self.focused_sea_orm_input().clear();
         } else if self
             .localization
             .matches_key("backspace", key.modifiers, key.code)
         {
-            self.language_search.pop();
-            self.filter_languages();
+            self.focused_sea_orm_input().pop();
         } else if let KeyCode::Char(c) = key.code {
-            self.language_search.push(c);
-            self.filter_languages();
+            if key.modifiers.contains(KeyModifiers::CONTROL) {
+                return false;
+            }
+            self.focused_sea_orm_input().push(c);
+        } else {
+            return false;
         }
+        true
     }
 
-    /// Handles events for the new app dialog
-    fn handle_new_app_dialog_events(&mut self, key: KeyEvent) {
+    /// The focused input of the SeaORM config dialog
+    fn focused_sea_orm_input(&mut self) -> &mut String {
+        if self.sea_orm_output_dir_focused {
+            &mut self.sea_orm_output_dir_input
+        } else {
+            &mut self.sea_orm_database_url_input
+        }
+    }
+
+    /// Saves the SeaORM config dialog inputs to the workspace's .rext/seaorm.toml, then
+    /// generates the entities
+    ///
+    /// Both inputs are required, the dialog stays open until they're filled in.
+    fn save_sea_orm_config_and_generate(&mut self) {
+        let database_url = self.sea_orm_database_url_input.trim();
+        let output_dir = self.sea_orm_output_dir_input.trim();
+        if database_url.is_empty() || output_dir.is_empty() {
+            return;
+        }
+
+        let config = SeaOrmConfig {
+            database_url: database_url.to_string(),
+            output_dir: output_dir.to_string(),
+        };
+        let saved = match self.workspace_dir() {
+            Ok(dir) => {
+                save_sea_orm_config_in(&dir, &config).map_err(|e| e.user_message().to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(error) = saved {
+            let message = self
+                .localization
+                .msg("sea_orm_config_error")
+                .replace("{error}", &error);
+            self.push_notification(message, true);
+            return;
+        }
+
+        self.close_dialog();
+        self.generate_sea_orm_entities_with_open_api_schema();
+    }
+
+    /// Handles events for the telemetry consent dialog, Esc declines
+    fn handle_telemetry_consent_dialog_events(&mut self, key: KeyEvent) -> bool {
         if self
             .localization
             .matches_key("left", key.modifiers, key.code)
         {
-            // Navigate to Create button (0)
-            self.new_app_button_selected = 0;
+            self.focused_button = 0;
         } else if self
             .localization
             .matches_key("right", key.modifiers, key.code)
         {
-            // Navigate to Cancel button (1)
-            self.new_app_button_selected = 1;
+            self.focused_button = 1;
         } else if self
             .localization
             .matches_key("enter", key.modifiers, key.code)
         {
-            // Handle button action based on selection
-            if self.new_app_button_selected == 0 {
-                // Create button - scaffold new app
-                self.handle_new_app_creation();
-            } else {
-                // Cancel button - quit application
-                self.quit();
-            }
+            self.set_telemetry_enabled(self.focused_button == 0);
         } else if self
             .localization
             .matches_key("escape", key.modifiers, key.code)
         {
-            self.close_dialog();
-        } else if self
+            self.set_telemetry_enabled(false);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Saves all the settings as they're shown to the preferences, showing the error if it fails
+    ///
+    /// Writing the whole state means a successful save also saves the changes of earlier failed
+    /// saves, so `has_unsaved_changes` is only cleared when nothing is left unsaved. The file is
+    /// left untouched if it already holds the same settings.
+    fn save_settings(&mut self) {
+        let preferences = UserPreferences {
+            theme: self.current_theme.clone(),
+            language: self.current_language_code.clone(),
+            recently_used_themes: self.recently_used_themes.iter().cloned().collect(),
+            language_sticky_search: self.sticky_language_search,
+            telemetry_enabled: self.telemetry.is_some(),
+            telemetry_prompted: self.telemetry_prompted,
+        };
+        let result = self.rext_config_dir().and_then(|dir| {
+            if load_preferences_in(&dir).is_ok_and(|saved| saved == preferences) {
+                return Ok(());
+            }
+            save_preferences_in(&dir, &preferences)
+        });
+        match result {
+            Ok(()) => self.has_unsaved_changes = false,
+            Err(e) => {
+                self.last_error = Some(e.user_message().to_string());
+                self.has_unsaved_changes = true;
+            }
+        }
+    }
+
+    /// Saves the telemetry choice, starts or stops recording, and closes the consent dialog
+    fn set_telemetry_enabled(&mut self, enabled: bool) {
+        self.telemetry = enabled
+            .then_some(self.config_dir.as_ref())
+            .flatten()
+            .map(|dir| TelemetryRecorder::new(dir.telemetry_path()));
+        self.telemetry_prompted = true;
+        self.save_settings();
+        self.close_dialog();
+    }
+
+    /// Records a rext-core operation in the session log and appends it to tui.log in the
+    /// config directory
+    ///
+    /// `detail` is what the operation acted on if it succeeded, the error otherwise.
+    fn record_operation(&mut self, operation: &str, success: bool, detail: String) {
+        let record = OperationRecord::now(operation, success, detail);
+        // Operations can create or remove the app, even when they fail halfway
+        self.rext_app_cache_dirty = true;
+        if let Some(config_dir) = &self.config_dir {
+            if let Err(e) = record.append_to(&config_dir.operation_log_path()) {
+                log::warn!("failed to write operation log: {}", e);
+            }
+        }
+        self.operation_records.push_back(record);
+        if self.operation_records.len() > MAX_OPERATION_RECORDS {
+            self.operation_records.pop_front();
+        }
+    }
+
+    /// Records an action in the local telemetry file, if the user opted in
+    fn record_telemetry(&self, action: &str) {
+        if let Some(telemetry) = &self.telemetry {
+            if let Err(e) = telemetry.record(action) {
+                log::warn!("failed to record telemetry: {}", e);
+            }
+        }
+    }
+
+    /// Handles events for the error dialog, Enter or Esc dismisses it
+    fn handle_error_dialog_events(&mut self, key: KeyEvent) -> bool {
+        if self
             .localization
-            .matches_key("quit", key.modifiers, key.code)
+            .matches_key("enter", key.modifiers, key.code)
             || self
                 .localization
-                .matches_key("quit_combo", key.modifiers, key.code)
+                .matches_key("escape", key.modifiers, key.code)
         {
-            // Include option to quit from new app dialog
-            self.quit();
+            self.last_error = None;
+            match self.dialog_before_error.take() {
+                Some(dialog) if dialog != DialogType::None => self.set_dialog(dialog),
+                _ => self.close_dialog(),
+            }
+            true
+        } else {
+            false
         }
     }
 
     /// Handles events for the main application
-    fn handle_main_app_events(&mut self, key: KeyEvent) {
+    fn handle_main_app_events(&mut self, key: KeyEvent) -> bool {
         if self
             .localization
             .matches_key("quit", key.modifiers, key.code)
@@ -971,50 +3623,543 @@ impl App {
                 .matches_key("quit_combo", key.modifiers, key.code)
             || self
                 .localization
-                .matches_key("escape", key.modifiers, key.code)
-        {
-            self.quit();
-        } else if self
-            .localization
-            .matches_key("add_endpoint", key.modifiers, key.code)
-        {
-            self.open_dialog(DialogType::ApiEndpoint);
-        } else if self.localization.matches_key(
-            "generate_sea_orm_entities_with_open_api_schema",
-            key.modifiers,
-            key.code,
-        ) {
-            self.generate_sea_orm_entities_with_open_api_schema();
-        } else if self
-            .localization
-            .matches_key("settings", key.modifiers, key.code)
-        {
-            self.open_dialog(DialogType::Settings);
+                .matches_key("escape", key.modifiers, key.code)
+        {
+            self.quit();
+        } else if self
+            .localization
+            .matches_key("reload", key.modifiers, key.code)
+        {
+            match self.reload_config() {
+                Ok(()) => {
+                    let message = self.localization.msg("config_reloaded").to_string();
+                    self.push_notification(message, false);
+                }
+                Err(e) => {
+                    let message = self
+                        .localization
+                        .msg("config_reload_error")
+                        .replace("{error}", e.user_message());
+                    self.push_notification(message, true);
+                }
+            }
+        } else if self
+            .localization
+            .matches_key("recent_themes", key.modifiers, key.code)
+        {
+            self.open_recent_themes_dialog();
+        } else if self
+            .localization
+            .matches_key("undo", key.modifiers, key.code)
+        {
+            self.undo();
+        } else if self
+            .localization
+            .matches_key("sea_orm_config", key.modifiers, key.code)
+        {
+            self.open_sea_orm_config_dialog();
+        } else if self
+            .localization
+            .matches_key("add_endpoint", key.modifiers, key.code)
+        {
+            self.open_dialog(DialogType::ApiEndpoint);
+        } else if self.localization.matches_key(
+            "generate_sea_orm_entities_with_open_api_schema",
+            key.modifiers,
+            key.code,
+        ) {
+            self.generate_sea_orm_entities_with_open_api_schema();
+        } else if self
+            .localization
+            .matches_key("settings", key.modifiers, key.code)
+        {
+            self.open_dialog(DialogType::Settings);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Reloads the config, theme and localization from disk without restarting
+    fn reload_config(&mut self) -> Result<(), RextTuiError> {
+        invalidate_config_cache();
+        self.config_cache = Some(load_config()?);
+        self.invalidate_theme_cache();
+        let config_dir = self.rext_config_dir()?;
+        // A fresh install has no saved preferences yet, which isn't an error
+        let preferences = if config_dir.preferences_path().exists() {
+            load_preferences_in(&config_dir)?
+        } else {
+            load_preferences_in(&config_dir).unwrap_or_default()
+        };
+        self.current_theme = preferences.theme;
+        let language = preferences.language;
+        invalidate_localization_cache(&language);
+        self.localization.reload(&language)?;
+        self.current_language_code = language;
+        Ok(())
+    }
+
+    /// Resets the current theme to the default if it isn't in the loaded config
+    ///
+    /// The saved theme may reference a theme that was since removed from the config,
+    /// so the saved preference is fixed and the user is notified.
+    fn validate_current_theme(&mut self) {
+        let Ok(themes) = get_available_themes(None) else {
+            return;
+        };
+        if themes.contains(&self.current_theme) {
+            return;
+        }
+
+        let message = self
+            .localization
+            .msg("theme_not_found")
+            .replace("{theme}", &self.current_theme);
+        self.current_theme = "rust".to_string();
+        self.save_settings();
+        self.push_notification(message, true);
+    }
+
+    /// Adds a notification to show on the main screen, dropping the oldest beyond
+    /// [`MAX_NOTIFICATIONS`]
+    fn push_notification(&mut self, message: String, is_error: bool) {
+        self.notifications.push(Notification { message, is_error });
+        if self.notifications.len() > MAX_NOTIFICATIONS {
+            self.notifications.remove(0);
+        }
+    }
+
+    /// Opens the settings dialog
+    pub fn open_settings_dialog(&mut self) {
+        self.open_dialog(DialogType::Settings);
+    }
+
+    /// Opens the API endpoint creation dialog
+    pub fn open_api_endpoint_dialog(&mut self) {
+        self.open_dialog(DialogType::ApiEndpoint);
+    }
+
+    /// Opens the language selection dialog
+    pub fn open_language_selection_dialog(&mut self) {
+        self.open_language_dialog();
+    }
+
+    /// Opens the SeaORM config dialog, pre-filled from the workspace's .rext/seaorm.toml if it
+    /// exists
+    pub fn open_sea_orm_config_dialog(&mut self) {
+        let config = self
+            .workspace_dir()
+            .ok()
+            .and_then(|dir| load_sea_orm_config_in(&dir).ok())
+            .unwrap_or_else(|| SeaOrmConfig {
+                database_url: String::new(),
+                output_dir: DEFAULT_SEA_ORM_OUTPUT_DIR.to_string(),
+            });
+        self.sea_orm_database_url_input = config.database_url;
+        self.sea_orm_output_dir_input = config.output_dir;
+        self.sea_orm_output_dir_focused = false;
+        self.open_dialog(DialogType::SeaOrmConfig);
+    }
+
+    /// Closes whichever dialog is currently open
+    pub fn close_current_dialog(&mut self) {
+        self.close_dialog();
+    }
+
+    /// Captures the theme, language and open dialog, see [`AppBuilder::from_snapshot`]
+    pub fn snapshot(&self) -> AppSnapshot {
+        AppSnapshot {
+            theme: self.current_theme.clone(),
+            language: self.current_language_code.clone(),
+            last_dialog: self.current_dialog.as_log_str().to_string(),
+        }
+    }
+
+    /// Applies a session snapshot, skipping a theme that's no longer in the config
+    fn restore_snapshot(&mut self, snapshot: AppSnapshot) {
+        if self.theme_names().contains(&snapshot.theme) {
+            self.current_theme = snapshot.theme;
+        }
+        if self.localization.reload(&snapshot.language).is_ok() {
+            self.current_language_code = snapshot.language;
+        }
+        match snapshot.last_dialog.as_str() {
+            "api_endpoint" => self.open_dialog(DialogType::ApiEndpoint),
+            "settings" => self.open_dialog(DialogType::Settings),
+            "language" => self.open_language_dialog(),
+            "recent_themes" => self.open_recent_themes_dialog(),
+            _ => {}
+        }
+    }
+
+    /// Display name of the current language, or its code if it isn't in the config
+    fn current_language_display(&self) -> &str {
+        self.config_cache
+            .as_ref()
+            .and_then(|config| config.localization.get(&self.current_language_code))
+            .map_or(&self.current_language_code, |language| &language.display)
+    }
+
+    /// The theme names in the loaded config, sorted
+    pub fn theme_names(&self) -> Vec<String> {
+        let mut themes: Vec<String> = self
+            .config_cache
+            .iter()
+            .flat_map(|config| config.themes.keys().cloned())
+            .collect();
+        themes.sort();
+        themes
+    }
+
+    /// The languages in the loaded config as (code, display name), sorted by display name
+    pub fn language_names(&self) -> Vec<(String, String)> {
+        let mut languages: Vec<(String, String)> = self
+            .config_cache
+            .iter()
+            .flat_map(|config| config.localization.iter())
+            .map(|(code, language)| (code.clone(), language.display.clone()))
+            .collect();
+        languages.sort_by(|a, b| a.1.cmp(&b.1));
+        languages
+    }
+
+    /// How long [`App::new`] took to initialize the app
+    pub fn startup_duration(&self) -> Duration {
+        self.startup_duration
+    }
+
+    /// How long the app has been running
+    pub fn session_duration(&self) -> Duration {
+        self.session_start.elapsed()
+    }
+
+    /// How many times each dialog was opened this session, keyed by [`DialogType::as_log_str`]
+    pub fn dialog_stats(&self) -> &HashMap<String, usize> {
+        &self.dialog_open_counts
+    }
+
+    /// Changes the current dialog, firing the dialog change hook if the dialog changed
+    ///
+    /// Every dialog opener goes through here, so it also counts dialog opens for [`App::dialog_stats`].
+    fn set_dialog(&mut self, dialog_type: DialogType) {
+        if self.current_dialog == dialog_type {
+            return;
+        }
+        if let Some(hook) = &self.dialog_change_hook {
+            hook(&self.current_dialog, &dialog_type);
+        }
+        if self.current_dialog == DialogType::Settings && self.theme_cycled {
+            self.theme_cycled = false;
+            self.record_recent_theme();
+            self.save_settings();
+        }
+        if dialog_type != DialogType::None {
+            self.record_telemetry(&format!("open_{}", dialog_type.as_log_str()));
+            *self
+                .dialog_open_counts
+                .entry(dialog_type.as_log_str().to_string())
+                .or_insert(0) += 1;
+        }
+        self.current_dialog = dialog_type;
+    }
+
+    /// Opens the given dialog and clears the API endpoint input
+    fn open_dialog(&mut self, dialog_type: DialogType) {
+        if dialog_type == DialogType::Settings {
+            let saved = self
+                .saved_scroll_positions
+                .get(DialogType::Settings.as_log_str())
+                .copied()
+                .unwrap_or(0);
+            self.select_setting(saved);
+        }
+        self.set_dialog(dialog_type);
+        self.api_endpoint_input.clear();
+        self.history_selected = None;
+        self.api_endpoint_overwrite_confirmed = false;
+    }
+
+    /// Shows the overwrite warning if the typed endpoint was already created and it isn't shown yet
+    ///
+    /// Returns whether the warning was just shown, the endpoint is created on the next submit.
+    fn ask_to_overwrite_endpoint(&mut self) -> bool {
+        if self.api_endpoint_overwrite_confirmed
+            || !self.endpoint_history.contains(&self.api_endpoint_input)
+        {
+            return false;
+        }
+        self.api_endpoint_overwrite_confirmed = true;
+        true
+    }
+
+    /// Creates an API endpoint, making it undoable and adding it to the history
+    fn create_api_endpoint(&mut self, api_endpoint_name: String) {
+        let api_endpoint_name = self.handle_api_endpoint_creation(api_endpoint_name);
+        if !api_endpoint_name.is_empty() {
+            self.record_telemetry("create_endpoint");
+            self.push_undo(UndoAction::CreateEndpoint(api_endpoint_name.clone()));
+        }
+        self.record_endpoint_history(api_endpoint_name);
+    }
+
+    /// Adds an undo step, dropping the oldest beyond [`MAX_UNDO_STEPS`]
+    fn push_undo(&mut self, action: UndoAction) {
+        self.undo_stack.push(action);
+        if self.undo_stack.len() > MAX_UNDO_STEPS {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    /// Undoes the latest operation and notifies the user of the result
+    ///
+    /// Destroying an app asks for confirmation first, the undo runs once it's confirmed.
+    pub fn undo(&mut self) {
+        match self.undo_stack.last() {
+            None => {}
+            Some(UndoAction::DestroyApp(_)) => {
+                self.focused_button = 1; // Default to No, destroying can't be undone
+                self.set_dialog(DialogType::ConfirmDestroy { undo: true });
+            }
+            Some(UndoAction::CreateEndpoint(_)) => self.run_undo(),
+        }
+    }
+
+    /// Undoes the latest operation without asking, see [`App::undo`]
+    fn run_undo(&mut self) {
+        let Some(action) = self.undo_stack.pop() else {
+            return;
+        };
+
+        let (operation, target, result, message) = match &action {
+            UndoAction::CreateEndpoint(name) => (
+                "delete_api_endpoint",
+                name.clone(),
+                self.rext_core.delete_api_endpoint(name).inspect(|_| {
+                    self.endpoint_history.retain(|entry| entry != name);
+                    self.save_endpoint_history();
+                }),
+                self.localization
+                    .msg("undo_create_endpoint")
+                    .replace("{name}", name),
+            ),
+            UndoAction::DestroyApp(dir) => (
+                "destroy_app",
+                dir_name(dir),
+                self.rext_core.destroy_rext_app(dir),
+                self.localization
+                    .msg("undo_scaffold_app")
+                    .replace("{dir_name}", &dir_name(dir)),
+            ),
+        };
+
+        match result {
+            Ok(()) => {
+                self.record_operation(operation, true, target);
+                self.push_notification(message, false);
+            }
+            Err(e) => {
+                self.record_operation(operation, false, e.to_string());
+                let message = self
+                    .localization
+                    .msg("undo_error")
+                    .replace("{error}", &e.to_string());
+                self.push_notification(message, true);
+            }
+        }
+    }
+
+    /// Handles API endpoint creation - placeholder for future functionality
+    fn handle_api_endpoint_creation(&self, api_endpoint_name: String) -> String {
+        // For now, just return the API endpoint name
+        // Later this will interface with rext-core functionality
+        api_endpoint_name
+    }
+
+    /// Gets up to 3 endpoint history entries that prefix-match the typed input, most recent first
+    fn endpoint_suggestions(&self) -> Vec<&str> {
+        let prefix = if self.history_selected.is_some() {
+            &self.history_prefix
+        } else {
+            &self.api_endpoint_input
+        };
+        matching_endpoints(&self.endpoint_history, prefix)
+    }
+
+    /// Cycles through the endpoint history suggestions, filling the input with the selected one
+    fn cycle_endpoint_suggestion(&mut self) {
+        if self.history_selected.is_none() {
+            self.history_prefix = self.api_endpoint_input.clone();
+        }
+
+        let suggestions = self.endpoint_suggestions();
+        if suggestions.is_empty() {
+            return;
+        }
+
+        let next_index = match self.history_selected {
+            Some(index) => (index + 1) % suggestions.len(),
+            None => 0,
+        };
+        self.api_endpoint_input = suggestions[next_index].to_string();
+        self.history_selected = Some(next_index);
+        self.api_endpoint_overwrite_confirmed = false;
+    }
+
+    /// Saves the workspace's endpoint history to ~/.rext/endpoint_history.toml
+    fn save_endpoint_history(&self) {
+        if let Ok(project) = &self.current_dir_result {
+            let _ = self
+                .rext_config_dir()
+                .and_then(|dir| save_endpoint_history_in(&dir, project, &self.endpoint_history));
+        }
+    }
+
+    /// Adds an endpoint name to the workspace's history and saves it
+    fn record_endpoint_history(&mut self, api_endpoint_name: String) {
+        if api_endpoint_name.is_empty() {
+            return;
+        }
+
+        self.endpoint_history
+            .retain(|name| name != &api_endpoint_name);
+        self.endpoint_history.push(api_endpoint_name);
+        if self.endpoint_history.len() > MAX_ENDPOINT_HISTORY {
+            let overflow = self.endpoint_history.len() - MAX_ENDPOINT_HISTORY;
+            self.endpoint_history.drain(..overflow);
+        }
+        self.save_endpoint_history();
+    }
+
+    /// Whether the open dialog has a focused text input that typed characters go to
+    fn text_input_focused(&self) -> bool {
+        match self.current_dialog {
+            DialogType::ApiEndpoint | DialogType::Language | DialogType::SeaOrmConfig => true,
+            DialogType::NewApp => self.new_app_name_focused,
+            _ => false,
+        }
+    }
+
+    /// Selects the first entry of the open list dialog, for the `goto_top` key sequence
+    fn goto_top(&mut self) {
+        match self.current_dialog {
+            DialogType::Settings => self.select_setting(0),
+            DialogType::Language => {
+                self.language_selected = 0;
+                self.language_list_state.select(Some(0));
+            }
+            DialogType::RecentThemes if !self.recently_used_themes.is_empty() => {
+                self.recent_themes_list_state.select(Some(0));
+            }
+            _ => {}
+        }
+    }
+
+    /// Buffers a key press and checks it against all multi-key sequence actions
+    fn feed_key_sequence(&mut self, key: KeyEvent) -> KeySequenceStep {
+        if self.key_sequence_timed_out() {
+            self.clear_key_sequence();
+        }
+
+        self.key_sequence_buffer.push((key.modifiers, key.code));
+        let mut step = self.match_key_sequence_buffer();
+        if matches!(step, KeySequenceStep::Unmatched) && self.key_sequence_buffer.len() > 1 {
+            // The key broke the pending sequence, but it may start a new one
+            self.key_sequence_buffer = vec![(key.modifiers, key.code)];
+            step = self.match_key_sequence_buffer();
+        }
+
+        match step {
+            KeySequenceStep::Pending => {
+                if self.key_sequence_buffer.len() == 1 {
+                    self.key_sequence_started = Some(Instant::now());
+                }
+            }
+            KeySequenceStep::Complete(_) | KeySequenceStep::Unmatched => self.clear_key_sequence(),
+        }
+        step
+    }
+
+    /// Matches the key sequence buffer against all multi-key sequence actions
+    fn match_key_sequence_buffer(&self) -> KeySequenceStep {
+        let mut pending = false;
+        for action in self.localization.key_sequence_actions() {
+            match self
+                .localization
+                .matches_key_partial(action, &self.key_sequence_buffer)
+            {
+                PartialMatchResult::FullMatch => {
+                    return KeySequenceStep::Complete(action.to_string());
+                }
+                PartialMatchResult::PartialMatch => pending = true,
+                PartialMatchResult::NoMatch => {}
+            }
+        }
+
+        if pending {
+            KeySequenceStep::Pending
+        } else {
+            KeySequenceStep::Unmatched
         }
     }
 
-    /// Opens the API endpoint creation dialog
-    fn open_dialog(&mut self, dialog_type: DialogType) {
-        self.current_dialog = dialog_type;
-        self.api_endpoint_input.clear();
+    /// Has the buffered key sequence been waiting longer than [`KEY_SEQUENCE_TIMEOUT`]?
+    fn key_sequence_timed_out(&self) -> bool {
+        self.key_sequence_started
+            .is_some_and(|started| started.elapsed() > KEY_SEQUENCE_TIMEOUT)
     }
 
-    /// Handles API endpoint creation - placeholder for future functionality
-    fn handle_api_endpoint_creation(&self, api_endpoint_name: String) -> String {
-        // For now, just return the API endpoint name
-        // Later this will interface with rext-core functionality
-        api_endpoint_name
+    /// Clears the key sequence buffer
+    fn clear_key_sequence(&mut self) {
+        self.key_sequence_buffer.clear();
+        self.key_sequence_started = None;
     }
 
     /// Set running to false to quit the application.
     fn quit(&mut self) {
         self.running = false;
+        log::debug!("Dialog open counts: {:?}", self.dialog_open_counts);
+    }
+
+    /// The colors of the current theme, only loaded again when the theme changed
+    /// or the cache was invalidated
+    fn theme(&mut self) -> Theme {
+        if let Some(theme) = self.cached_theme {
+            if self.cached_theme_name == self.current_theme {
+                return theme;
+            }
+        }
+
+        let (primary, text, background) = self.load_colors();
+        let theme = Theme {
+            primary,
+            text,
+            background,
+        };
+        self.cached_theme = Some(theme);
+        self.cached_theme_name = self.current_theme.clone();
+        theme
+    }
+
+    /// Clears the cached theme colors, so the next frame loads them again
+    fn invalidate_theme_cache(&mut self) {
+        self.cached_theme = None;
     }
 
     /// Loads the color configs from the current theme, falling back to defaults if loading fails
+    ///
+    /// A readable theme file at `REXT_THEME_FILE` takes precedence over the current theme.
     fn load_colors(&self) -> (Color, Color, Color) {
+        let theme_file_colors = std::env::var_os(THEME_FILE_ENV_VAR)
+            .and_then(|path| load_theme_from_file(Path::new(&path)).ok());
+
         // Try to load colors from the current theme, fall back to defaults on error
-        match load_theme_colors(&self.current_theme) {
+        let colors = match (theme_file_colors, &self.config_cache) {
+            (Some(colors), _) => Ok(colors),
+            (None, Some(config)) => theme_colors_from(&self.current_theme, config),
+            (None, None) => load_theme_colors(&self.current_theme),
+        };
+        match colors {
             Ok(colors) => {
                 let primary_color =
                     Color::Rgb(colors.primary.r, colors.primary.g, colors.primary.b);
@@ -1036,56 +4181,97 @@ impl App {
         }
     }
 
-    /// Cycles to the next available theme
-    fn cycle_theme(&mut self) {
-        if let Ok(themes) = get_available_themes() {
-            if let Some(current_index) = themes.iter().position(|t| t == &self.current_theme) {
-                let next_index = (current_index + 1) % themes.len();
-                self.current_theme = themes[next_index].clone();
+    /// Cycles to the next available theme, or the previous one if `reverse`
+    fn cycle_theme(&mut self, reverse: bool) {
+        if let Ok(themes) = get_available_themes(Some(&self.current_theme)) {
+            if themes.first() == Some(&self.current_theme) {
+                // The current theme is listed first, so its neighbors are at both ends
+                let next = if reverse {
+                    themes.len() - 1
+                } else {
+                    1 % themes.len()
+                };
+                self.current_theme = themes[next].clone();
+                self.invalidate_theme_cache();
+                self.theme_cycled = true;
 
                 // Save the new theme selection
-                let _ = save_current_theme(&self.current_theme);
+                self.save_settings();
             }
         }
     }
 
+    /// Opens the recent themes dialog with the most recent theme selected
+    fn open_recent_themes_dialog(&mut self) {
+        self.set_dialog(DialogType::RecentThemes);
+        let selected = (!self.recently_used_themes.is_empty()).then_some(0);
+        self.recent_themes_list_state.select(selected);
+    }
+
+    /// Switches to a theme and saves it
+    fn select_theme(&mut self, theme: String) {
+        self.current_theme = theme;
+        self.invalidate_theme_cache();
+        self.record_recent_theme();
+        self.save_settings();
+    }
+
+    /// Moves the current theme to the front of the recently used themes
+    fn record_recent_theme(&mut self) {
+        self.record_telemetry("change_theme");
+        self.recently_used_themes
+            .retain(|theme| *theme != self.current_theme);
+        self.recently_used_themes
+            .push_front(self.current_theme.clone());
+        self.recently_used_themes.truncate(MAX_RECENT_THEMES);
+    }
+
     /// Opens the language selection dialog
     fn open_language_dialog(&mut self) {
-        self.current_dialog = DialogType::Language;
-        self.language_search.clear();
-        self.language_selected = 0;
+        self.set_dialog(DialogType::Language);
+        if !self.sticky_language_search {
+            self.language_search.clear();
+        }
         self.filter_languages();
-    }
 
-    /// Selects a language and closes the dialog
-    fn select_language(&mut self, language_code: String) {
-        // Save the selected language to config
-        if let Err(_) = save_current_language(&language_code) {
-            // Handle error gracefully - in production, you might want to show an error message
-            return;
+        if self.sticky_language_search {
+            // Keep the previous results, with the current language selected if it's among them
+            self.language_selected = self
+                .filtered_languages
+                .iter()
+                .position(|(code, _)| *code == self.current_language_code)
+                .unwrap_or(0);
+        } else if let Some(&saved) = self
+            .saved_scroll_positions
+            .get(DialogType::Language.as_log_str())
+            .filter(|&&saved| saved < self.filtered_languages.len())
+        {
+            self.language_selected = saved;
         }
+    }
 
+    /// Selects a language, saves it and closes the dialog
+    fn select_language(&mut self, language_code: String) {
         // Reload the localization with the new language
-        if let Err(_) = self.localization.reload(&language_code) {
-            // Handle error gracefully - fallback to English if reload fails
+        if let Err(e) = self.localization.reload(&language_code) {
+            // Fallback to English if reload fails
+            self.last_error = Some(e.user_message().to_string());
             let _ = self.localization.reload("en");
+            self.current_language_code = "en".to_string();
+        } else {
+            self.current_language_code = language_code;
+            self.record_telemetry("change_language");
         }
 
+        self.save_settings();
         self.close_dialog();
     }
 
     /// Filters the languages based on the search input
     fn filter_languages(&mut self) {
-        let search_term = self.language_search.to_lowercase();
-
         if let Ok(available_languages) = get_available_languages_with_display() {
-            self.filtered_languages = available_languages
-                .into_iter()
-                .filter(|(code, display)| {
-                    code.to_lowercase().contains(&search_term)
-                        || display.to_lowercase().contains(&search_term)
-                })
-                .collect();
+            self.filtered_languages = filter_languages(&self.language_search, available_languages);
+            move_language_first(&mut self.filtered_languages, &self.current_language_code);
         } else {
             self.filtered_languages = Vec::new();
         }
@@ -1102,22 +4288,41 @@ impl App {
         // The current implementation allows navigation even with one item
     }
 
+    /// The workspace directory rext-core operations run in
+    fn workspace_dir(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        match &self.current_dir_result {
+            Ok(dir) => Ok(dir.clone()),
+            Err(e) => Err(e.to_string().into()),
+        }
+    }
+
     /// Handles the creation of a new Rext app by calling the scaffold function
     fn handle_new_app_creation(&mut self) {
         // Call the scaffold function from rext_core
-        match rext_core::scaffold_rext_app() {
-            Ok(_) => {
+        match self.workspace_dir().and_then(|dir| {
+            self.rext_core
+                .scaffold_rext_app_named(&dir, &self.new_app_name_input)
+        }) {
+            Ok(app_dir) => {
+                self.record_telemetry("scaffold_app");
+                self.record_operation("scaffold_app", true, self.new_app_name_input.clone());
+                self.push_undo(UndoAction::DestroyApp(app_dir.clone()));
+                // The new app is the workspace from now on, it's refreshed on the next tick
+                self.current_dir_result = Ok(app_dir);
+                self.endpoint_history =
+                    workspace_endpoint_history(self.config_dir.as_ref(), &self.current_dir_result);
                 self.new_app_message = Some(
                     self.localization
                         .ui("new_app_success_message")
-                        .replace("{dir_name}", &self.current_dir_name),
+                        .replace("{dir_name}", &self.new_app_name_input),
                 );
             }
-            Err(_) => {
+            Err(e) => {
+                self.record_operation("scaffold_app", false, e.to_string());
                 self.new_app_message = Some(
                     self.localization
                         .ui("new_app_error_message")
-                        .replace("{dir_name}", &self.current_dir_name),
+                        .replace("{dir_name}", &self.new_app_name_input),
                 );
             }
         }
@@ -1125,32 +4330,759 @@ impl App {
 
     /// Closes the current dialog and resets dialog-specific state
     fn close_dialog(&mut self) {
-        self.current_dialog = DialogType::None;
+        match self.current_dialog {
+            DialogType::Settings => {
+                self.saved_scroll_positions.insert(
+                    DialogType::Settings.as_log_str().to_string(),
+                    self.settings_selected,
+                );
+            }
+            DialogType::Language => {
+                self.saved_scroll_positions.insert(
+                    DialogType::Language.as_log_str().to_string(),
+                    self.language_selected,
+                );
+            }
+            _ => {}
+        }
+
+        self.set_dialog(DialogType::None);
         self.api_endpoint_input.clear();
-        self.language_search.clear();
+        self.history_selected = None;
+        self.api_endpoint_overwrite_confirmed = false;
+        self.api_endpoint_created_count = 0;
+        self.history_prefix.clear();
+        if !self.sticky_language_search {
+            self.language_search.clear();
+        }
         self.language_selected = 0;
-        self.settings_selected = 0;
+        self.select_setting(0);
+        self.focused_button = 0;
+        self.new_app_name_focused = true;
         self.filtered_languages.clear();
+        self.clear_key_sequence();
     }
 
     /// Generates SeaORM entities with OpenAPI schema
     fn generate_sea_orm_entities_with_open_api_schema(&mut self) {
         // Call the generate_sea_orm_entities_with_open_api_schema function from rext_core
-        match rext_core::generate_sea_orm_entities_with_open_api_schema() {
-            Ok(_) => {
-                self.new_app_message = Some(
-                    self.localization
-                        .ui("new_app_success_message")
-                        .replace("{dir_name}", &self.current_dir_name),
-                );
+        match self.workspace_dir().and_then(|dir| {
+            self.rext_core
+                .generate_sea_orm_entities_with_open_api_schema(&dir)
+        }) {
+            Ok(count) => {
+                self.record_telemetry("generate_entities");
+                self.record_operation("generate_entities", true, count.to_string());
+                let message = self
+                    .localization
+                    .msg("generate_entities_success")
+                    .replace("{count}", &count.to_string())
+                    .replace("{dir_name}", &self.workspace.dir_name);
+                self.push_notification(message, false);
             }
-            Err(_) => {
-                self.new_app_message = Some(
-                    self.localization
-                        .ui("new_app_error_message")
-                        .replace("{dir_name}", &self.current_dir_name),
-                );
+            Err(e) => {
+                self.record_operation("generate_entities", false, e.to_string());
+                let message = self
+                    .localization
+                    .msg("generate_entities_error")
+                    .replace("{dir_name}", &self.workspace.dir_name)
+                    .replace("{error}", &e.to_string());
+                self.push_notification(message, true);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An app with its own empty config directory, so tests don't touch ~/.rext/
+    fn test_config_dir() -> RextConfigDir {
+        static NEXT_DIR: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let index = NEXT_DIR.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let path =
+            std::env::temp_dir().join(format!("rext_tui_app_{}_{}", std::process::id(), index));
+        let _ = std::fs::remove_dir_all(&path);
+        RextConfigDir::new(path).unwrap()
+    }
+
+    fn test_app() -> App {
+        AppBuilder::new().config_dir(test_config_dir()).build()
+    }
+
+    /// A writer whose output can still be read once it's moved into a backend
+    struct SharedWriter(Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn input_modes_are_written_through_the_backend() {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut backend = CrosstermBackend::new(SharedWriter(Rc::clone(&output)));
+
+        backend.enable_input_modes().unwrap();
+        let written = String::from_utf8_lossy(&output.borrow()).into_owned();
+        assert!(written.contains("\x1b[?2004h") && written.contains("\x1b[?1004h"));
+        assert!(written.contains("\x1b[>1u"));
+        backend.disable_input_modes().unwrap();
+        let written = String::from_utf8_lossy(&output.borrow()).into_owned();
+        assert!(written.contains("\x1b[?2004l") && written.contains("\x1b[?1004l"));
+        assert!(written.contains("\x1b[<1u"));
+        assert!(!written.contains("\x1b[?1049l"));
+
+        // Only the safety net leaves the alternate screen
+        backend.restore_terminal().unwrap();
+        assert!(String::from_utf8_lossy(&output.borrow()).contains("\x1b[?1049l"));
+    }
+
+    #[test]
+    fn read_event_retries_interrupted_reads() {
+        let mut results = vec![
+            Ok(Event::Key(KeyEvent::from(KeyCode::Char('s')))),
+            Err(std::io::Error::from(std::io::ErrorKind::Interrupted)),
+        ];
+        let event = read_event_with_retry(|| results.pop().unwrap()).unwrap();
+
+        let mut app = test_app();
+        app.handle_event(event);
+        assert_eq!(app.current_dialog, DialogType::Settings);
+    }
+
+    #[test]
+    fn current_language_is_listed_first() {
+        let language = |code: &str, display: &str| (code.to_string(), display.to_string());
+        let mut languages = vec![
+            language("de", "Deutsch"),
+            language("en", "English"),
+            language("fr", "Français"),
+        ];
+
+        move_language_first(&mut languages, "fr");
+        assert_eq!(
+            languages,
+            vec![
+                language("fr", "Français"),
+                language("de", "Deutsch"),
+                language("en", "English"),
+            ]
+        );
+
+        // Unknown codes leave the order alone
+        move_language_first(&mut languages, "es");
+        assert_eq!(languages[0].0, "fr");
+    }
+
+    #[test]
+    fn recent_themes_are_deduplicated_and_capped() {
+        let mut app = test_app();
+        let themes: Vec<String> = (0..MAX_RECENT_THEMES + 2)
+            .map(|i| format!("theme_{}", i))
+            .collect();
+        for theme in &themes {
+            app.select_theme(theme.clone());
+        }
+        assert_eq!(app.recently_used_themes.len(), MAX_RECENT_THEMES);
+        assert_eq!(app.recently_used_themes.front(), themes.last());
+
+        // Selecting a listed theme again moves it to the front instead of repeating it
+        app.select_theme(themes[3].clone());
+        assert_eq!(app.recently_used_themes.len(), MAX_RECENT_THEMES);
+        assert_eq!(app.recently_used_themes[0], themes[3]);
+        assert_eq!(
+            app.recently_used_themes
+                .iter()
+                .filter(|theme| **theme == themes[3])
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn endpoint_fn_signature_preview() {
+        let expected = "async fn get_users(/* ... */) -> impl Responder";
+        assert_eq!(endpoint_name_to_fn_signature("get_users", "GET"), expected);
+        assert_eq!(endpoint_name_to_fn_signature("users", "GET"), expected);
+        assert_eq!(endpoint_name_to_fn_signature("Get Users", "GET"), expected);
+    }
+
+    #[test]
+    fn pop_word_deletes_the_last_word() {
+        let mut input = "get_user_posts".to_string();
+        pop_word(&mut input);
+        assert_eq!(input, "get_user_");
+
+        // Trailing separators go with the word before them
+        pop_word(&mut input);
+        assert_eq!(input, "get_");
+
+        let mut input = "endpoint".to_string();
+        pop_word(&mut input);
+        assert_eq!(input, "");
+        pop_word(&mut input);
+        assert_eq!(input, "");
+
+        let mut input = "größe_ändern".to_string();
+        pop_word(&mut input);
+        assert_eq!(input, "größe_");
+    }
+
+    #[test]
+    fn language_search_is_unicode_case_insensitive() {
+        let display = "Türkçe";
+        assert!(fold_for_search(display).contains(&fold_for_search("türk")));
+        assert!(fold_for_search(display).contains(&fold_for_search("TÜRK")));
+        // Decomposed "u" + combining diaeresis matches the precomposed "ü"
+        assert!(fold_for_search(display).contains(&fold_for_search("tu\u{308}rk")));
+    }
+
+    fn sample_languages() -> Vec<(String, String)> {
+        [
+            ("de", "Deutsch"),
+            ("en", "English"),
+            ("fr", "Français"),
+            ("tr", "Türkçe"),
+        ]
+        .into_iter()
+        .map(|(code, display)| (code.to_string(), display.to_string()))
+        .collect()
+    }
+
+    fn codes(languages: &[(String, String)]) -> Vec<&str> {
+        languages.iter().map(|(code, _)| code.as_str()).collect()
+    }
+
+    #[test]
+    fn empty_search_keeps_every_language_in_order() {
+        let languages = filter_languages("", sample_languages());
+        assert_eq!(languages, sample_languages());
+    }
+
+    #[test]
+    fn search_matches_code_or_display_name() {
+        assert_eq!(codes(&filter_languages("fr", sample_languages())), ["fr"]);
+        assert_eq!(codes(&filter_languages("deut", sample_languages())), ["de"]);
+        assert_eq!(codes(&filter_languages("en", sample_languages())), ["en"]);
+        assert_eq!(
+            codes(&filter_languages("e", sample_languages())),
+            ["de", "en", "tr"]
+        );
+    }
+
+    #[test]
+    fn search_ignores_case_and_normalization() {
+        assert_eq!(codes(&filter_languages("ENGL", sample_languages())), ["en"]);
+        assert_eq!(
+            codes(&filter_languages("FRANÇ", sample_languages())),
+            ["fr"]
+        );
+        assert_eq!(
+            codes(&filter_languages("tu\u{308}rk", sample_languages())),
+            ["tr"]
+        );
+    }
+
+    #[test]
+    fn search_without_matches_is_empty() {
+        assert!(filter_languages("xyz", sample_languages()).is_empty());
+        assert!(filter_languages("de", Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn render_settings_dialog_from_state() {
+        let localization = Localization::new("en").unwrap();
+        let state = AppState {
+            localization: &localization,
+            current_dialog: &DialogType::Settings,
+            current_theme: "nord",
+            recently_used_themes: &VecDeque::new(),
+            api_endpoint_input: "",
+            api_endpoint_overwrite_confirmed: false,
+            api_endpoint_created_count: 0,
+            endpoint_suggestions: Vec::new(),
+            history_selected: None,
+            language_search: "",
+            language_selected: 0,
+            filtered_languages: &[],
+            current_language_code: "en",
+            current_language_display: "English",
+            show_language_codes: true,
+            sticky_language_search: true,
+            focused_button: 0,
+            new_app_name_input: "",
+            new_app_name_focused: true,
+            new_app_message: None,
+            workspace_dir_name: "test-project",
+            sea_orm_database_url_input: "",
+            sea_orm_output_dir_input: "",
+            sea_orm_output_dir_focused: false,
+        };
+        let theme = Theme {
+            primary: Color::Rgb(255, 107, 53),
+            text: Color::Rgb(204, 204, 204),
+            background: Color::Rgb(26, 26, 26),
+        };
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|frame| {
+                let mut list_state = ListState::default().with_selected(Some(0));
+                App::render_settings_dialog(&state, &mut list_state, frame, &theme)
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Theme: nord"));
+        assert!(rendered.contains("Active: theme=nord  lang=English"));
+
+        // Only the selected option is filled with the primary color
+        let cell_of = |text: &str| {
+            (0..20)
+                .find_map(|y| {
+                    let row: String = (0..80).map(|x| buffer[(x, y)].symbol()).collect();
+                    let x = row
+                        .find(text)
+                        .map(|byte| row[..byte].chars().count() as u16)?;
+                    Some(&buffer[(x, y)])
+                })
+                .unwrap()
+        };
+        assert_eq!(cell_of("Theme: nord").bg, theme.primary);
+        assert_eq!(cell_of("Theme: nord").fg, theme.text);
+        assert_eq!(cell_of("Change Language").bg, theme.background);
+    }
+
+    #[test]
+    fn dialogs_stay_within_terminal_bounds() {
+        for (width, height) in [
+            (10, 3),
+            (40, 16),
+            (60, 20),
+            (80, 24),
+            (120, 40),
+            (300, 100),
+            (u16::MAX, u16::MAX),
+        ] {
+            let area = Rect::new(0, 0, width, height);
+            for content_height in [0, 8, 14, percent_of(height, 60).min(15)] {
+                let rect = dialog_rect(area, content_height);
+                assert!(area.contains(rect.as_position()));
+                assert!(rect.right() <= area.right() && rect.bottom() <= area.bottom());
+                assert!(rect.width <= 80);
+                assert!(rect.width >= MIN_DIALOG_WIDTH.min(width));
+                assert!(rect.height >= MIN_DIALOG_HEIGHT.min(height));
+            }
+        }
+
+        let mut app = test_app();
+        for dialog in [
+            DialogType::ApiEndpoint,
+            DialogType::Settings,
+            DialogType::Language,
+            DialogType::NewApp,
+            DialogType::SeaOrmConfig,
+            DialogType::TelemetryConsent,
+            DialogType::ConfirmDestroy { undo: false },
+        ] {
+            app.current_dialog = dialog;
+            for (width, height) in [(MIN_WIDTH, MIN_HEIGHT), (80, 24), (200, 60)] {
+                let mut terminal =
+                    ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height))
+                        .unwrap();
+                terminal.draw(|frame| app.render(frame)).unwrap();
             }
         }
     }
+
+    #[test]
+    fn second_instance_lock_fails() {
+        let path = std::env::temp_dir().join(format!("rext_tui_lock_{}", std::process::id()));
+        let lock = acquire_instance_lock(&path).unwrap();
+        assert!(matches!(
+            acquire_instance_lock(&path),
+            Err(RextTuiError::AlreadyRunning)
+        ));
+
+        drop(lock);
+        assert!(acquire_instance_lock(&path).is_ok());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn only_contended_locks_mean_already_running() {
+        let would_block = std::io::Error::from(std::io::ErrorKind::WouldBlock);
+        assert!(matches!(
+            instance_lock_error(would_block),
+            RextTuiError::AlreadyRunning
+        ));
+        assert!(matches!(
+            instance_lock_error(fs2::lock_contended_error()),
+            RextTuiError::AlreadyRunning
+        ));
+
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert!(matches!(
+            instance_lock_error(denied),
+            RextTuiError::InstanceLock(e) if e.kind() == std::io::ErrorKind::PermissionDenied
+        ));
+    }
+
+    #[test]
+    fn telemetry_consent_is_asked_once_and_defaults_to_no() {
+        let mut app = test_app();
+        assert!(!app.telemetry_prompted);
+        app.ask_for_telemetry_consent();
+        assert_eq!(app.current_dialog, DialogType::TelemetryConsent);
+        assert_eq!(app.focused_button, 1);
+
+        app.on_key_event(KeyEvent::from(KeyCode::Left));
+        assert_eq!(app.focused_button, 0);
+        app.on_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.telemetry.is_none());
+        assert!(app.telemetry_prompted);
+        assert_ne!(app.current_dialog, DialogType::TelemetryConsent);
+
+        app.ask_for_telemetry_consent();
+        assert_ne!(app.current_dialog, DialogType::TelemetryConsent);
+        assert!(
+            load_preferences_in(app.config_dir.as_ref().unwrap())
+                .unwrap()
+                .telemetry_prompted
+        );
+    }
+
+    #[test]
+    fn every_dialog_renders() {
+        let mut app = test_app();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        for dialog in DialogType::all_variants() {
+            app.set_dialog(dialog.clone());
+            terminal.draw(|frame| app.render(frame)).unwrap();
+        }
+
+        // Names are unique, so they can be used as keys
+        let names: std::collections::HashSet<&str> = DialogType::all_variants()
+            .iter()
+            .map(DialogType::name)
+            .collect();
+        assert_eq!(names.len(), DialogType::all_variants().len());
+    }
+
+    #[test]
+    fn render_stats_count_frames() {
+        let mut app = test_app();
+        assert_eq!(app.render_stats(), (0, Duration::ZERO));
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| app.render_timed(frame)).unwrap();
+        terminal.draw(|frame| app.render_timed(frame)).unwrap();
+        let (frames, duration) = app.render_stats();
+        assert_eq!(frames, 2);
+        assert!(duration > Duration::ZERO);
+    }
+
+    #[test]
+    fn failed_settings_save_shows_modified_until_saved() {
+        let mut app = test_app();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        let shows_modified = |terminal: &ratatui::Terminal<ratatui::backend::TestBackend>| {
+            terminal
+                .backend()
+                .buffer()
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+                .contains("[modified]")
+        };
+
+        // A directory in the way of preferences.toml makes the theme save fail
+        let config_dir = app.config_dir.clone().unwrap();
+        std::fs::create_dir(config_dir.preferences_path()).unwrap();
+        app.cycle_theme(false);
+        assert!(app.has_unsaved_changes);
+        assert!(app.last_error.is_some());
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        assert!(shows_modified(&terminal));
+
+        // The next successful save also saves the theme
+        std::fs::remove_dir(config_dir.preferences_path()).unwrap();
+        app.last_error = None;
+        app.sticky_language_search = !app.sticky_language_search;
+        app.save_settings();
+        assert!(!app.has_unsaved_changes);
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        assert!(!shows_modified(&terminal));
+        let saved = load_preferences_in(&config_dir).unwrap();
+        assert_eq!(saved.theme, app.current_theme);
+        assert_eq!(saved.language_sticky_search, app.sticky_language_search);
+    }
+
+    #[test]
+    fn ctrl_enter_creates_endpoints_without_closing_the_dialog() {
+        let mut app = test_app().with_mock_rext_core(Box::new(MockRextCore::default()));
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        let mut rendered = |app: &mut App| {
+            terminal.draw(|frame| app.render(frame)).unwrap();
+            let buffer = terminal.backend().buffer();
+            buffer
+                .content()
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        let ctrl_enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL);
+        app.set_dialog(DialogType::ApiEndpoint);
+
+        app.on_paste("users");
+        app.on_key_event(ctrl_enter);
+        assert_eq!(app.current_dialog, DialogType::ApiEndpoint);
+        assert!(app.api_endpoint_input.is_empty());
+        assert!(rendered(&mut app).contains("Created 1 endpoint "));
+
+        app.on_paste("posts");
+        app.on_key_event(ctrl_enter);
+        assert_eq!(app.current_dialog, DialogType::ApiEndpoint);
+        assert!(rendered(&mut app).contains("Created 2 endpoints"));
+    }
+
+    #[test]
+    fn left_cycles_themes_in_reverse() {
+        let mut app = test_app();
+        let themes = get_available_themes(None).unwrap();
+        app.current_theme = themes[0].clone();
+        app.set_dialog(DialogType::Settings);
+
+        app.on_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.current_theme, themes[1 % themes.len()]);
+        app.on_key_event(KeyEvent::from(KeyCode::Left));
+        assert_eq!(app.current_theme, themes[0]);
+        app.on_key_event(KeyEvent::from(KeyCode::Left));
+        assert_eq!(app.current_theme, themes[themes.len() - 1]);
+
+        // Left only cycles on the theme option
+        app.select_setting(1);
+        app.on_key_event(KeyEvent::from(KeyCode::Left));
+        assert_eq!(app.current_theme, themes[themes.len() - 1]);
+    }
+
+    #[test]
+    fn accessibility_mode_renders_plain_numbered_text() {
+        let mut app = test_app();
+        app.accessibility_mode = true;
+        app.set_dialog(DialogType::Settings);
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+
+        assert!(rendered.contains(&format!("1. Theme: {} (selected)", app.current_theme)));
+        assert!(rendered.contains("2. Change Language"));
+        assert!(rendered.contains("6. "));
+        assert!(!rendered.contains(['│', '─', '║', '═']));
+        assert!(
+            buffer
+                .content()
+                .iter()
+                .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset)
+        );
+
+        assert!(
+            AppBuilder::new()
+                .accessibility_mode(true)
+                .build()
+                .accessibility_mode
+        );
+    }
+
+    #[test]
+    fn unreadable_working_dir_skips_the_rext_app_check() {
+        let mut app = test_app().with_mock_rext_core(Box::new(MockRextCore::default()));
+        app.current_dir_result = Err(std::io::Error::from(std::io::ErrorKind::NotFound));
+        app.on_tick();
+        assert_eq!(app.current_dialog, DialogType::None);
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Working directory unavailable"));
+
+        app.current_dir_result = Ok(PathBuf::from("."));
+        app.on_tick();
+        assert_eq!(app.current_dialog, DialogType::NewApp);
+    }
+
+    #[test]
+    fn focus_gained_checks_the_rext_app_again() {
+        let exists = Rc::new(Cell::new(true));
+        let mut app = test_app().with_mock_rext_core(Box::new(MockRextCore {
+            app_exists: Rc::clone(&exists),
+            ..MockRextCore::default()
+        }));
+        app.current_dir_result = Ok(PathBuf::from("."));
+        app.on_tick();
+        assert_eq!(app.current_dialog, DialogType::None);
+        assert!(app.workspace.is_rext_app);
+
+        // Removed externally, the cached result stands until focus is regained
+        exists.set(false);
+        app.on_tick();
+        assert_eq!(app.current_dialog, DialogType::None);
+
+        app.handle_event(Event::FocusGained);
+        app.on_tick();
+        assert_eq!(app.current_dialog, DialogType::NewApp);
+        assert!(!app.workspace.is_rext_app);
+    }
+
+    #[test]
+    fn rext_app_is_checked_in_the_working_dir() {
+        let mock = MockRextCore::default();
+        let calls = Rc::clone(&mock.calls);
+        let mut app = AppBuilder::new()
+            .config_dir(test_config_dir())
+            .working_dir(PathBuf::from("/tmp/test-project"))
+            .build()
+            .with_mock_rext_core(Box::new(mock));
+        app.on_tick();
+
+        assert_eq!(
+            *calls.borrow(),
+            [(
+                "check_for_rext_app".to_string(),
+                PathBuf::from("/tmp/test-project")
+            )]
+        );
+        assert_eq!(app.current_dialog, DialogType::NewApp);
+    }
+
+    #[test]
+    fn scaffolding_into_a_subdirectory_moves_the_workspace() {
+        let mock = MockRextCore::default();
+        let calls = Rc::clone(&mock.calls);
+        let mut app = AppBuilder::new()
+            .config_dir(test_config_dir())
+            .working_dir(PathBuf::from("/tmp/projects"))
+            .build()
+            .with_mock_rext_core(Box::new(mock));
+        app.on_tick();
+        assert_eq!(app.current_dialog, DialogType::NewApp);
+
+        app.new_app_name_input = "my_app".to_string();
+        app.handle_new_app_creation();
+        app.close_dialog();
+        app.on_tick();
+
+        let app_dir = PathBuf::from("/tmp/projects/my_app");
+        assert_eq!(
+            calls.borrow()[1..],
+            [
+                ("scaffold_rext_app".to_string(), app_dir.clone()),
+                ("check_for_rext_app".to_string(), app_dir.clone()),
+            ]
+        );
+        assert_eq!(app.workspace.dir_name, "my_app");
+        assert!(app.workspace.is_rext_app);
+        assert_eq!(app.current_dialog, DialogType::None);
+        assert_eq!(app.undo_stack, [UndoAction::DestroyApp(app_dir)]);
+    }
+
+    #[test]
+    fn dismissing_an_error_returns_to_the_previous_dialog() {
+        let mock = MockRextCore {
+            app_exists: Rc::new(Cell::new(true)),
+            ..MockRextCore::default()
+        };
+        let mut app = test_app().with_mock_rext_core(Box::new(mock));
+        app.open_settings_dialog();
+        app.last_error = Some("Failed to write config file".to_string());
+        app.on_tick();
+        assert!(matches!(app.current_dialog, DialogType::Error { .. }));
+
+        app.on_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.current_dialog, DialogType::Settings);
+        assert_eq!(app.last_error, None);
+    }
+
+    #[test]
+    fn api_endpoint_input_is_boxed() {
+        let mut app = test_app();
+        app.set_dialog(DialogType::ApiEndpoint);
+        app.on_paste("users");
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("││users_"));
+    }
+
+    #[test]
+    fn theme_is_cached_until_it_changes() {
+        let mut app = test_app();
+        let theme = app.theme();
+        assert_eq!(app.cached_theme, Some(theme));
+        assert_eq!(app.cached_theme_name, app.current_theme);
+
+        // A stale cache entry is reused while the theme name doesn't change
+        let stale = Theme {
+            primary: Color::Red,
+            text: Color::Red,
+            background: Color::Red,
+        };
+        app.cached_theme = Some(stale);
+        assert_eq!(app.theme(), stale);
+
+        app.invalidate_theme_cache();
+        assert_eq!(app.theme(), theme);
+
+        app.cached_theme = Some(stale);
+        app.current_theme = "nord".to_string();
+        assert_ne!(app.theme(), stale);
+        assert_eq!(app.cached_theme_name, "nord");
+    }
+
+    #[test]
+    fn small_terminal_pauses_until_resized() {
+        let mut app = test_app();
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(30, 10)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        assert!(app.terminal_too_small);
+
+        app.handle_event(Event::Resize(35, 20));
+        assert!(app.terminal_too_small);
+
+        app.handle_event(Event::Resize(MIN_WIDTH, MIN_HEIGHT));
+        assert!(!app.terminal_too_small);
+    }
 }
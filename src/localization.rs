@@ -42,10 +42,25 @@
 //! keys are for both displaying and controlling which key should be pressed on the keyboard for an action.
 //! Each key entry serves dual purpose - both for display and actual key binding.
 //!
+//! ## Language Tags
+//! Languages are selected with BCP 47 tags, separated by `-` or `_` in any case.
+//! The language, script (4 letters) and region (2 letters or 3 digits) subtags are supported,
+//! e.g. `pt-BR` or `zh-Hant-TW`, variant and extension subtags are ignored.
+//! A missing tag falls back to shorter tags, then English: `pt-BR` tries `pt-BR`, `pt`, `en`.
+//!
+//! ## Platform Overrides
+//! Bindings can be overridden per platform in `[keys.overrides.macos]`, `[keys.overrides.linux]`
+//! and `[keys.overrides.windows]`, the section for the current platform replaces the base bindings.
+//!
+//! ## Key Sequences
+//! A `[keys]` entry can also be an array of key strings, which must be pressed one after
+//! another (vim style), e.g. `goto_top = ["Ctrl+K", "Ctrl+K"]`.
+//! See [`Localization::matches_key_partial`].
+//!
 //! ## Supported Key Formats
 //! The localization system supports a wide range of key formats (case-insensitive):
 //! - **Single characters**: "a", "q", "1", "2"
-//! - **Special keys**: "Esc"/"Escape", "Enter"/"Return", "Backspace"/"Back", "Tab", "Delete"/"Del", "Insert"/"Ins"
+//! - **Special keys**: "Esc"/"Escape", "Enter"/"Return", "Backspace"/"Back", "Tab", "Delete"/"Del", "Insert"/"Ins", "Space"/"Spacebar"
 //! - **Arrow keys**: "Up", "Down", "Left", "Right", "UpArrow", "DownArrow", "LeftArrow", "RightArrow"
 //! - **Navigation keys**: "Home", "End", "PageUp"/"PgUp", "PageDown"/"PgDn"
 //! - **Function keys**: "F1", "F2", ..., "F12"
@@ -54,39 +69,297 @@
 //! The system validates all key bindings on startup and will warn about invalid key strings.
 use crossterm::event::{KeyCode, KeyModifiers};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, Mutex};
 
 use crate::config;
 use crate::error::RextTuiError;
+use crate::keys;
 
 /// Stores the localized texts for the TUI from the localization directory
+///
+/// Entries in `[keys]` are either a single key string or an array of key strings for a
+/// multi-key sequence (e.g. `goto_top = ["Ctrl+K", "Ctrl+K"]`), sequences are stored in `key_sequences`.
+/// Bindings in the `[keys.overrides.<platform>]` section matching the current platform
+/// replace the base bindings when the file is loaded.
 #[derive(Debug, Deserialize, Clone)]
+#[serde(from = "RawLocalizedTexts")]
 pub struct LocalizedTexts {
     pub ui: HashMap<String, String>,
     pub messages: HashMap<String, String>,
     pub keys: HashMap<String, String>,
+    pub key_sequences: HashMap<String, Vec<String>>,
+}
+
+/// A `[keys]` entry as written in the localization file
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawKeyBinding {
+    Single(String),
+    Sequence(Vec<String>),
+}
+
+/// The `[keys]` section as written in the localization file
+#[derive(Deserialize)]
+struct RawKeys {
+    /// Platform specific bindings, keyed by platform ("macos", "linux", "windows")
+    #[serde(default)]
+    overrides: HashMap<String, HashMap<String, RawKeyBinding>>,
+    #[serde(flatten)]
+    bindings: HashMap<String, RawKeyBinding>,
+}
+
+/// Localization file layout before key sequences are split out of `[keys]`
+#[derive(Deserialize)]
+struct RawLocalizedTexts {
+    ui: HashMap<String, String>,
+    messages: HashMap<String, String>,
+    keys: RawKeys,
+}
+
+/// The platform name used to pick a `[keys.overrides.<platform>]` section
+fn current_platform() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "macos"
+    } else if cfg!(target_os = "windows") {
+        "windows"
+    } else {
+        "linux"
+    }
+}
+
+/// macOS glyphs for modifier and special key names, in replacement order
+const MAC_KEY_GLYPHS: &[(&str, &str)] = &[
+    ("Ctrl+", "⌃"),
+    ("Alt+", "⌥"),
+    ("Shift+", "⇧"),
+    ("Enter", "↵"),
+    ("Esc", "⎋"),
+];
+
+/// Replaces modifier and special key names with their macOS glyphs, `"Ctrl+C"` gives `"⌃C"`
+fn with_mac_key_glyphs(key: &str) -> String {
+    MAC_KEY_GLYPHS
+        .iter()
+        .fold(key.to_string(), |key, (name, glyph)| {
+            key.replace(name, glyph)
+        })
+}
+
+/// Normalizes a BCP 47 tag and lists it with its progressively shorter forms
+///
+/// Only the language, script and region subtags are kept, each in its canonical case,
+/// so `EN_us` gives `["en-US", "en"]`.
+fn normalize_language_tag(tag: &str) -> Vec<String> {
+    let mut subtags = tag.split(['-', '_']).filter(|s| !s.is_empty()).peekable();
+    let Some(language) = subtags.next() else {
+        return Vec::new();
+    };
+
+    let mut normalized = vec![language.to_ascii_lowercase()];
+    if let Some(script) =
+        subtags.next_if(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()))
+    {
+        normalized.push(script[..1].to_ascii_uppercase() + &script[1..].to_ascii_lowercase());
+    }
+    if let Some(region) = subtags.next_if(|s| {
+        (s.len() == 2 && s.chars().all(|c| c.is_ascii_alphabetic()))
+            || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+    }) {
+        normalized.push(region.to_ascii_uppercase());
+    }
+
+    (1..=normalized.len())
+        .rev()
+        .map(|len| normalized[..len].join("-"))
+        .collect()
+}
+
+/// Language tags to try for `tag` in order, ending with English
+///
+/// `pt-BR` gives `["pt-BR", "pt", "en"]`.
+fn locale_fallback_chain(tag: &str) -> Vec<String> {
+    let mut chain = normalize_language_tag(tag);
+    if chain.last().is_none_or(|last| last != "en") {
+        chain.push("en".to_string());
+    }
+    chain
+}
+
+impl From<RawLocalizedTexts> for LocalizedTexts {
+    fn from(raw: RawLocalizedTexts) -> Self {
+        let RawKeys {
+            mut overrides,
+            mut bindings,
+        } = raw.keys;
+
+        // Platform overrides take precedence over the base bindings
+        if let Some(platform_bindings) = overrides.remove(current_platform()) {
+            bindings.extend(platform_bindings);
+        }
+
+        let mut keys = HashMap::new();
+        let mut key_sequences = HashMap::new();
+        for (action, binding) in bindings {
+            match binding {
+                RawKeyBinding::Single(key_str) => {
+                    keys.insert(action, key_str);
+                }
+                RawKeyBinding::Sequence(key_strs) => {
+                    key_sequences.insert(action, key_strs);
+                }
+            }
+        }
+
+        Self {
+            ui: raw.ui,
+            messages: raw.messages,
+            keys,
+            key_sequences,
+        }
+    }
+}
+
+#[cfg(feature = "gettext")]
+impl LocalizedTexts {
+    /// Reads localized texts from a Gettext `.po` file
+    ///
+    /// Each msgid is a key and its msgstr the value. The section is set with an extracted
+    /// comment (`#. section: ui`, `#. section: messages` or `#. section: keys`) and applies to
+    /// that message and the ones after it, until the next section comment. Untranslated
+    /// messages and messages before the first section comment are skipped. The file needs a
+    /// complete Gettext header, as written by tools like Poedit or `msginit`.
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// #. section: ui
+    /// msgid "settings_title"
+    /// msgstr "Settings"
+    ///
+    /// #. section: keys
+    /// msgid "quit"
+    /// msgstr "q"
+    /// ```
+    pub fn from_po_file(path: &std::path::Path) -> Result<Self, RextTuiError> {
+        let catalog = polib::po_file::parse(path).map_err(RextTuiError::ParsePoFile)?;
+
+        let mut texts = Self {
+            ui: HashMap::new(),
+            messages: HashMap::new(),
+            keys: HashMap::new(),
+            key_sequences: HashMap::new(),
+        };
+        let mut section = None;
+        for message in catalog.messages() {
+            if let Some(name) = message
+                .comments()
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("section:"))
+            {
+                section = Some(name.trim().to_string());
+            }
+
+            let Ok(value) = message.msgstr() else {
+                continue;
+            };
+            if value.is_empty() {
+                continue;
+            }
+
+            let section_map = match section.as_deref() {
+                Some("ui") => &mut texts.ui,
+                Some("messages") => &mut texts.messages,
+                Some("keys") => &mut texts.keys,
+                _ => continue,
+            };
+            section_map.insert(message.msgid().to_string(), value.to_string());
+        }
+
+        Ok(texts)
+    }
+}
+
+/// Result of matching buffered key presses against a key sequence
+///
+/// - `FullMatch`: The buffer matches the whole sequence
+/// - `PartialMatch`: The buffer matches the start of the sequence, more keys are needed
+/// - `NoMatch`: The buffer doesn't match the sequence
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartialMatchResult {
+    FullMatch,
+    PartialMatch,
+    NoMatch,
+}
+
+/// What [`Localization::get`] returns for a key missing from both the current and English texts
+///
+/// - `Empty`: An empty string
+/// - `Warn`: The key, logging a warning with `log::warn!` the first time each key is missing
+/// - `FallbackKey`: The key as `[section.key]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingKeyPolicy {
+    Empty,
+    #[default]
+    Warn,
+    FallbackKey,
 }
 
 /// The localization system for the TUI
 pub struct Localization {
     texts: LocalizedTexts,
     fallback_texts: LocalizedTexts, // English as fallback
+    /// The requested language, used in missing key warnings
+    current_lang: String,
+    missing_key_policy: MissingKeyPolicy,
+    /// Index into `resolved_texts` per section (ui, messages, keys), built by `rebuild_index`
+    key_index: [HashMap<String, usize>; 3],
+    /// Texts of the current language with English filling the missing keys
+    resolved_texts: Vec<String>,
+    /// Missing keys already warned about with [`MissingKeyPolicy::Warn`], as `section.key`
+    warned_missing_keys: Mutex<HashSet<String>>,
+}
+
+/// Invalid key strings already warned about by [`Localization::parse_key_string`]
+static WARNED_KEY_STRINGS: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(Mutex::default);
+
+/// Adds `key` to the `warned` keys, returns whether it wasn't warned about before
+fn first_warning(warned: &Mutex<HashSet<String>>, key: String) -> bool {
+    warned
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(key)
+}
+
+/// Position of a section in [`Localization::key_index`]
+fn section_index(section: &str) -> Option<usize> {
+    match section {
+        "ui" => Some(0),
+        "messages" => Some(1),
+        "keys" => Some(2),
+        _ => None,
+    }
 }
 
 impl Localization {
     /// Creates a new localization system for the TUI, english is the fallback
+    ///
+    /// A missing regional language falls back to its parent first, `pt-BR` to `pt`.
     pub fn new(lang: &str) -> Result<Self, RextTuiError> {
         let fallback_texts = Self::load_language("en")?;
-        let texts = if lang == "en" {
-            fallback_texts.clone()
-        } else {
-            Self::load_language(lang).unwrap_or_else(|_| fallback_texts.clone())
-        };
+        let texts = Self::negotiate_language(lang, &fallback_texts);
 
-        let localization = Self {
+        let mut localization = Self {
             texts,
             fallback_texts,
+            current_lang: lang.to_string(),
+            missing_key_policy: MissingKeyPolicy::default(),
+            key_index: Default::default(),
+            resolved_texts: Vec::new(),
+            warned_missing_keys: Mutex::default(),
         };
+        localization.rebuild_index();
 
         // Validate key bindings on creation
         localization.validate_key_bindings();
@@ -94,14 +367,38 @@ impl Localization {
         Ok(localization)
     }
 
+    /// Creates a localization system from a Gettext `.po` file, english is the fallback
+    ///
+    /// See [`LocalizedTexts::from_po_file`] for the expected file layout.
+    #[cfg(feature = "gettext")]
+    pub fn from_po_file(path: &std::path::Path) -> Result<Self, RextTuiError> {
+        let mut localization = Self {
+            texts: LocalizedTexts::from_po_file(path)?,
+            fallback_texts: Self::load_language("en")?,
+            current_lang: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            missing_key_policy: MissingKeyPolicy::default(),
+            key_index: Default::default(),
+            resolved_texts: Vec::new(),
+            warned_missing_keys: Mutex::default(),
+        };
+        localization.rebuild_index();
+
+        localization.validate_key_bindings();
+
+        Ok(localization)
+    }
+
     /// Reloads the localization system with a new language
+    ///
+    /// If neither `lang` nor a parent language can be loaded, the error is returned
+    /// and the current texts are kept.
     pub fn reload(&mut self, lang: &str) -> Result<(), RextTuiError> {
-        let texts = if lang == "en" {
-            self.fallback_texts.clone()
-        } else {
-            Self::load_language(lang).unwrap_or_else(|_| self.fallback_texts.clone())
-        };
-        self.texts = texts;
+        self.texts = Self::load_language_or_parent(lang, &self.fallback_texts)?;
+        self.current_lang = lang.to_string();
+        self.rebuild_index();
 
         // Validate key bindings after reload
         self.validate_key_bindings();
@@ -109,6 +406,28 @@ impl Localization {
         Ok(())
     }
 
+    /// Rebuilds the lookup index used by [`Localization::get`] after the texts change
+    fn rebuild_index(&mut self) {
+        self.key_index = Default::default();
+        self.resolved_texts.clear();
+
+        // English first so the current language overrides it
+        for texts in [&self.fallback_texts, &self.texts] {
+            let sections = [&texts.ui, &texts.messages, &texts.keys];
+            for (index, section) in self.key_index.iter_mut().zip(sections) {
+                for (key, text) in section {
+                    match index.get(key) {
+                        Some(&i) => self.resolved_texts[i] = text.clone(),
+                        None => {
+                            index.insert(key.clone(), self.resolved_texts.len());
+                            self.resolved_texts.push(text.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Validates all key bindings in the current localization
     /// Prints warnings for any keys that cannot be parsed
     pub fn validate_key_bindings(&self) {
@@ -120,6 +439,14 @@ impl Localization {
             }
         }
 
+        for (action, key_strs) in &self.texts.key_sequences {
+            for key_str in key_strs {
+                if Self::parse_key_string(key_str).is_none() {
+                    invalid_keys.push((action.clone(), key_str.clone()));
+                }
+            }
+        }
+
         if !invalid_keys.is_empty() {
             eprintln!(
                 "Warning: Found {} invalid key binding(s) in localization:",
@@ -132,6 +459,59 @@ impl Localization {
         }
     }
 
+    /// Counts the entries in the current language as (ui, messages, keys)
+    pub fn count_keys(&self) -> (usize, usize, usize) {
+        (
+            self.texts.ui.len(),
+            self.texts.messages.len(),
+            self.texts.keys.len() + self.texts.key_sequences.len(),
+        )
+    }
+
+    /// Counts the entries in the English fallback as (ui, messages, keys)
+    fn fallback_count_keys(&self) -> (usize, usize, usize) {
+        (
+            self.fallback_texts.ui.len(),
+            self.fallback_texts.messages.len(),
+            self.fallback_texts.keys.len() + self.fallback_texts.key_sequences.len(),
+        )
+    }
+
+    /// Percentage of the English fallback entries covered by the current language
+    pub fn coverage_percent(&self) -> f32 {
+        let (ui, messages, keys) = self.count_keys();
+        let (fallback_ui, fallback_messages, fallback_keys) = self.fallback_count_keys();
+        let fallback_total = fallback_ui + fallback_messages + fallback_keys;
+        if fallback_total == 0 {
+            return 100.0;
+        }
+        (ui + messages + keys) as f32 / fallback_total as f32 * 100.0
+    }
+
+    /// Summarizes the coverage of the current language for validation output
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rext_tui::localization::Localization;
+    /// let localization = Localization::new("en").unwrap();
+    /// assert!(localization.coverage_summary().starts_with("100% coverage"));
+    /// ```
+    pub fn coverage_summary(&self) -> String {
+        let (ui, messages, keys) = self.count_keys();
+        let (fallback_ui, fallback_messages, fallback_keys) = self.fallback_count_keys();
+        format!(
+            "{:.0}% coverage ({}/{} ui, {}/{} messages, {}/{} keys)",
+            self.coverage_percent(),
+            ui,
+            fallback_ui,
+            messages,
+            fallback_messages,
+            keys,
+            fallback_keys
+        )
+    }
+
     /// Gets a list of all supported key string formats for documentation
     pub fn get_supported_key_formats() -> Vec<&'static str> {
         vec![
@@ -152,6 +532,8 @@ impl Localization {
             "Del",
             "Insert",
             "Ins",
+            "Space",
+            "Spacebar",
             // Arrow keys
             "Up",
             "Down",
@@ -190,6 +572,39 @@ impl Localization {
         ]
     }
 
+    /// Loads the first available language in the fallback chain of `lang`
+    fn negotiate_language(lang: &str, fallback_texts: &LocalizedTexts) -> LocalizedTexts {
+        locale_fallback_chain(lang)
+            .iter()
+            .find_map(|tag| match tag.as_str() {
+                "en" => Some(fallback_texts.clone()),
+                _ => Self::load_language(tag).ok(),
+            })
+            .unwrap_or_else(|| fallback_texts.clone())
+    }
+
+    /// Loads `lang` or the closest parent language (e.g. "fr" for "fr-CA"), without
+    /// falling back to English unless `lang` is English
+    ///
+    /// Returns the error of the first tag that failed to load if none of them could be loaded.
+    fn load_language_or_parent(
+        lang: &str,
+        fallback_texts: &LocalizedTexts,
+    ) -> Result<LocalizedTexts, RextTuiError> {
+        let mut first_error = None;
+        normalize_language_tag(lang)
+            .iter()
+            .find_map(|tag| match tag.as_str() {
+                "en" => Some(fallback_texts.clone()),
+                _ => Self::load_language(tag)
+                    .map_err(|e| first_error.get_or_insert(e))
+                    .ok(),
+            })
+            .ok_or_else(|| {
+                first_error.unwrap_or_else(|| RextTuiError::LanguageNotFound(lang.to_string()))
+            })
+    }
+
     /// Loads the localized texts for the TUI using the config system
     ///
     /// This loads from user overrides first, then falls back to embedded defaults.
@@ -201,22 +616,66 @@ impl Localization {
     /// Gets the localized text for the TUI
     /// section: The section of the text to get (ui, messages, keys)
     /// key: The key of the text to get (not keyboard key, the key in the toml file)
-    /// Returns the localized text
+    /// Returns the localized text, or what the [`MissingKeyPolicy`] gives if the key
+    /// is not found in either the current or the fallback language
     ///
     /// # Example
     ///
     /// ```rust
-    /// use rext_tui::localization::Localization;
-    /// let localization = Localization::new("en").unwrap();
+    /// use rext_tui::localization::{Localization, MissingKeyPolicy};
+    /// let mut localization = Localization::new("en").unwrap();
     /// let text = localization.get("ui", "add_api_endpoint");
     /// assert_eq!(text, "Add API endpoint");
+    ///
+    /// let missing = localization.get("ui", "does_not_exist");
+    /// assert_eq!(missing, "does_not_exist");
+    ///
+    /// localization.set_missing_key_policy(MissingKeyPolicy::FallbackKey);
+    /// assert_eq!(localization.get("ui", "does_not_exist"), "[ui.does_not_exist]");
     /// ```
-    pub fn get(&self, section: &str, key: &str) -> &str {
+    pub fn get(&self, section: &str, key: &str) -> Cow<'_, str> {
+        let Some(section_index) = section_index(section) else {
+            return Cow::Borrowed("Unknown section");
+        };
+
+        self.key_index[section_index]
+            .get(key)
+            .map(|&i| Cow::Borrowed(self.resolved_texts[i].as_str()))
+            .unwrap_or_else(|| self.missing_text(section, key))
+    }
+
+    /// The text for a missing key, following the [`MissingKeyPolicy`]
+    fn missing_text(&self, section: &str, key: &str) -> Cow<'_, str> {
+        match self.missing_key_policy {
+            MissingKeyPolicy::Empty => Cow::Borrowed(""),
+            MissingKeyPolicy::Warn => {
+                if first_warning(&self.warned_missing_keys, format!("{section}.{key}")) {
+                    log::warn!(
+                        "Missing localization key: section='{}' key='{}' lang='{}'",
+                        section,
+                        key,
+                        self.current_lang
+                    );
+                }
+                Cow::Owned(key.to_string())
+            }
+            MissingKeyPolicy::FallbackKey => Cow::Owned(format!("[{section}.{key}]")),
+        }
+    }
+
+    /// Sets what [`Localization::get`] returns for missing keys
+    pub fn set_missing_key_policy(&mut self, policy: MissingKeyPolicy) {
+        self.missing_key_policy = policy;
+    }
+
+    /// Looks up a text directly in the current and fallback maps, the reference for [`Localization::get`]
+    #[cfg(test)]
+    fn get_unindexed(&self, section: &str, key: &str) -> Cow<'_, str> {
         let section_map = match section {
             "ui" => &self.texts.ui,
             "messages" => &self.texts.messages,
             "keys" => &self.texts.keys,
-            _ => return "Unknown section",
+            _ => return Cow::Borrowed("Unknown section"),
         };
 
         section_map
@@ -231,152 +690,371 @@ impl Localization {
                 };
                 fallback_section.get(key)
             })
-            .map(|s| s.as_str())
-            .unwrap_or("Missing text")
+            .map(|s| Cow::Borrowed(s.as_str()))
+            .unwrap_or_else(|| self.missing_text(section, key))
     }
 
     /// Convenience method for UI texts
-    pub fn ui(&self, key: &str) -> &str {
+    pub fn ui(&self, key: &str) -> Cow<'_, str> {
         self.get("ui", key)
     }
 
     /// Convenience method for message texts
-    pub fn msg(&self, key: &str) -> &str {
+    pub fn msg(&self, key: &str) -> Cow<'_, str> {
         self.get("messages", key)
     }
 
     /// Convenience method for key texts
-    pub fn key(&self, key: &str) -> &str {
+    pub fn key(&self, key: &str) -> Cow<'_, str> {
         self.get("keys", key)
     }
 
+    /// Key text for display, with modifier and special keys as glyphs on macOS
+    ///
+    /// Use [`Localization::key`] for the raw key string, e.g. to match key events.
+    pub fn key_pretty(&self, action: &str) -> String {
+        let key = self.key(action);
+        if cfg!(target_os = "macos") {
+            with_mac_key_glyphs(&key)
+        } else {
+            key.into_owned()
+        }
+    }
+
     /// Gets the actual key code for a given action
     pub fn get_key_code(&self, action: &str) -> Option<(KeyModifiers, KeyCode)> {
         let key_str = self.key(action);
-        Self::parse_key_string(key_str)
-    }
-
-    /// Parses a key string into KeyModifiers and KeyCode
-    /// Supports common key formats including:
-    /// - Single characters: "q", "a", "1"
-    /// - Special keys: "Esc", "Enter", "Backspace", "Tab", "Delete"
-    /// - Arrow keys: "Up", "Down", "Left", "Right"
-    /// - Navigation: "Home", "End", "PageUp", "PageDown"
-    /// - Function keys: "F1", "F2", ..., "F12"
-    /// - Modifiers: "Ctrl+C", "Shift+Tab", "Alt+Enter"
-    /// - Case insensitive: "up", "UP", "Up" all work
+        Self::parse_key_string(&key_str)
+    }
+
+    /// Parses a key string into KeyModifiers and KeyCode, see [`keys::parse_key_string`]
+    ///
+    /// Invalid key strings in the localization files are logged as warnings, once per key string.
     fn parse_key_string(key_str: &str) -> Option<(KeyModifiers, KeyCode)> {
-        let key_str = key_str.trim();
+        keys::parse_key_string_strict(key_str)
+            .map_err(|error| {
+                if first_warning(&WARNED_KEY_STRINGS, key_str.to_string()) {
+                    log::warn!("{} in localization", error);
+                }
+            })
+            .ok()
+    }
 
-        // Handle modifier combinations
-        if key_str.contains('+') {
-            return Self::parse_modified_key(key_str);
+    /// Checks if the given key event matches the configured key for an action
+    pub fn matches_key(&self, action: &str, modifiers: KeyModifiers, code: KeyCode) -> bool {
+        let matched = self
+            .get_key_code(action)
+            .is_some_and(|expected| Self::key_matches(expected, modifiers, code));
+        if matched {
+            log::trace!("action: {}", action);
         }
+        matched
+    }
 
-        // Handle single keys (case-insensitive)
-        let normalized = key_str.to_lowercase();
-        match normalized.as_str() {
-            // Special keys
-            "esc" | "escape" => Some((KeyModifiers::NONE, KeyCode::Esc)),
-            "enter" | "return" => Some((KeyModifiers::NONE, KeyCode::Enter)),
-            "backspace" | "back" => Some((KeyModifiers::NONE, KeyCode::Backspace)),
-            "tab" => Some((KeyModifiers::NONE, KeyCode::Tab)),
-            "delete" | "del" => Some((KeyModifiers::NONE, KeyCode::Delete)),
-            "insert" | "ins" => Some((KeyModifiers::NONE, KeyCode::Insert)),
+    /// Checks buffered key presses against the configured key sequence for an action
+    ///
+    /// Actions bound to a single key are treated as a sequence of one key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crossterm::event::{KeyCode, KeyModifiers};
+    /// use rext_tui::localization::{Localization, PartialMatchResult};
+    /// let localization = Localization::new("en").unwrap();
+    /// let k = (KeyModifiers::CONTROL, KeyCode::Char('k'));
+    /// assert_eq!(
+    ///     localization.matches_key_partial("goto_top", &[k]),
+    ///     PartialMatchResult::PartialMatch
+    /// );
+    /// assert_eq!(
+    ///     localization.matches_key_partial("goto_top", &[k, k]),
+    ///     PartialMatchResult::FullMatch
+    /// );
+    /// ```
+    pub fn matches_key_partial(
+        &self,
+        action: &str,
+        buffer: &[(KeyModifiers, KeyCode)],
+    ) -> PartialMatchResult {
+        let sequence: Vec<Option<(KeyModifiers, KeyCode)>> = match self.get_key_sequence(action) {
+            Some(key_strs) => key_strs
+                .iter()
+                .map(|key_str| Self::parse_key_string(key_str))
+                .collect(),
+            None => vec![self.get_key_code(action)],
+        };
 
-            // Arrow keys
-            "up" | "uparrow" => Some((KeyModifiers::NONE, KeyCode::Up)),
-            "down" | "downarrow" => Some((KeyModifiers::NONE, KeyCode::Down)),
-            "left" | "leftarrow" => Some((KeyModifiers::NONE, KeyCode::Left)),
-            "right" | "rightarrow" => Some((KeyModifiers::NONE, KeyCode::Right)),
+        if buffer.is_empty() || buffer.len() > sequence.len() {
+            return PartialMatchResult::NoMatch;
+        }
 
-            // Navigation keys
-            "home" => Some((KeyModifiers::NONE, KeyCode::Home)),
-            "end" => Some((KeyModifiers::NONE, KeyCode::End)),
-            "pageup" | "pgup" => Some((KeyModifiers::NONE, KeyCode::PageUp)),
-            "pagedown" | "pgdn" => Some((KeyModifiers::NONE, KeyCode::PageDown)),
+        let prefix_matches = buffer
+            .iter()
+            .zip(&sequence)
+            .all(|(&(modifiers, code), expected)| {
+                expected.is_some_and(|expected| Self::key_matches(expected, modifiers, code))
+            });
 
-            // Function keys
-            "f1" => Some((KeyModifiers::NONE, KeyCode::F(1))),
-            "f2" => Some((KeyModifiers::NONE, KeyCode::F(2))),
-            "f3" => Some((KeyModifiers::NONE, KeyCode::F(3))),
-            "f4" => Some((KeyModifiers::NONE, KeyCode::F(4))),
-            "f5" => Some((KeyModifiers::NONE, KeyCode::F(5))),
-            "f6" => Some((KeyModifiers::NONE, KeyCode::F(6))),
-            "f7" => Some((KeyModifiers::NONE, KeyCode::F(7))),
-            "f8" => Some((KeyModifiers::NONE, KeyCode::F(8))),
-            "f9" => Some((KeyModifiers::NONE, KeyCode::F(9))),
-            "f10" => Some((KeyModifiers::NONE, KeyCode::F(10))),
-            "f11" => Some((KeyModifiers::NONE, KeyCode::F(11))),
-            "f12" => Some((KeyModifiers::NONE, KeyCode::F(12))),
-
-            // Single character keys
-            single_char if single_char.len() == 1 => {
-                let ch = key_str.chars().next()?; // Use original case for character
-                Some((KeyModifiers::NONE, KeyCode::Char(ch)))
-            }
+        if !prefix_matches {
+            PartialMatchResult::NoMatch
+        } else if buffer.len() == sequence.len() {
+            PartialMatchResult::FullMatch
+        } else {
+            PartialMatchResult::PartialMatch
+        }
+    }
 
-            // Unknown key
-            _ => {
-                eprintln!("Warning: Unknown key string '{}' in localization", key_str);
-                None
+    /// Gets the names of all actions bound to a multi-key sequence
+    pub fn key_sequence_actions(&self) -> Vec<&str> {
+        let mut actions: Vec<&str> = self
+            .texts
+            .key_sequences
+            .keys()
+            .chain(self.fallback_texts.key_sequences.keys())
+            .map(|action| action.as_str())
+            .collect();
+        actions.sort();
+        actions.dedup();
+        actions
+    }
+
+    /// Gets the key sequence for an action, falling back to English
+    fn get_key_sequence(&self, action: &str) -> Option<&Vec<String>> {
+        self.texts
+            .key_sequences
+            .get(action)
+            .or_else(|| self.fallback_texts.key_sequences.get(action))
+    }
+
+    /// Checks a single key press against an expected key
+    fn key_matches(
+        (expected_modifiers, expected_code): (KeyModifiers, KeyCode),
+        modifiers: KeyModifiers,
+        code: KeyCode,
+    ) -> bool {
+        // For character keys, check both lowercase and uppercase
+        match (expected_code, code) {
+            (KeyCode::Char(expected), KeyCode::Char(actual)) => {
+                expected_modifiers == modifiers
+                    && (expected == actual
+                        || expected.to_ascii_lowercase() == actual.to_ascii_lowercase())
             }
+            _ => expected_modifiers == modifiers && expected_code == code,
         }
     }
+}
 
-    /// Parses modified key combinations like "Ctrl+C", "Shift+Tab", "Alt+Enter"
-    fn parse_modified_key(key_str: &str) -> Option<(KeyModifiers, KeyCode)> {
-        let parts: Vec<&str> = key_str.split('+').collect();
-        if parts.len() != 2 {
-            eprintln!(
-                "Warning: Invalid key combination '{}' in localization",
-                key_str
-            );
-            return None;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let modifier_str = parts[0].trim().to_lowercase();
-        let key_part = parts[1].trim();
-
-        let modifiers = match modifier_str.as_str() {
-            "ctrl" | "control" => KeyModifiers::CONTROL,
-            "shift" => KeyModifiers::SHIFT,
-            "alt" => KeyModifiers::ALT,
-            _ => {
-                eprintln!(
-                    "Warning: Unknown modifier '{}' in key combination '{}'",
-                    modifier_str, key_str
-                );
-                return None;
-            }
-        };
+    #[test]
+    fn mac_key_glyphs_replace_key_names() {
+        assert_eq!(with_mac_key_glyphs("Ctrl+C"), "⌃C");
+        assert_eq!(with_mac_key_glyphs("Shift+Enter"), "⇧↵");
+        assert_eq!(with_mac_key_glyphs("Alt+Esc"), "⌥⎋");
+        assert_eq!(with_mac_key_glyphs("q"), "q");
 
-        // Parse the key part (recursively, but without modifiers)
-        if let Some((_, key_code)) = Self::parse_key_string(key_part) {
-            Some((modifiers, key_code))
+        let localization = Localization::new("en").unwrap();
+        let expected = if cfg!(target_os = "macos") {
+            "⌃C"
         } else {
-            eprintln!(
-                "Warning: Invalid key '{}' in combination '{}'",
-                key_part, key_str
-            );
-            None
-        }
+            "Ctrl+C"
+        };
+        assert_eq!(localization.key_pretty("quit_combo"), expected);
     }
 
-    /// Checks if the given key event matches the configured key for an action
-    pub fn matches_key(&self, action: &str, modifiers: KeyModifiers, code: KeyCode) -> bool {
-        if let Some((expected_modifiers, expected_code)) = self.get_key_code(action) {
-            // For character keys, check both lowercase and uppercase
-            match (expected_code, code) {
-                (KeyCode::Char(expected), KeyCode::Char(actual)) => {
-                    expected_modifiers == modifiers
-                        && (expected == actual
-                            || expected.to_ascii_lowercase() == actual.to_ascii_lowercase())
+    #[test]
+    fn indexed_get_matches_unindexed_lookup() {
+        for lang in ["en", "fr"] {
+            let localization = Localization::new(lang).unwrap();
+            for (section, map) in [
+                ("ui", &localization.fallback_texts.ui),
+                ("messages", &localization.fallback_texts.messages),
+                ("keys", &localization.fallback_texts.keys),
+            ] {
+                for key in map.keys().map(String::as_str).chain(["does_not_exist"]) {
+                    assert_eq!(
+                        localization.get(section, key),
+                        localization.get_unindexed(section, key)
+                    );
                 }
-                _ => expected_modifiers == modifiers && expected_code == code,
             }
-        } else {
-            false
+            assert_eq!(
+                localization.get("other", "quit"),
+                localization.get_unindexed("other", "quit")
+            );
         }
     }
+
+    #[test]
+    fn missing_key_policy_controls_missing_text() {
+        let mut localization = Localization::new("en").unwrap();
+        assert_eq!(localization.get("ui", "does_not_exist"), "does_not_exist");
+        assert_eq!(localization.get("ui", "does_not_exist"), "does_not_exist");
+        assert_eq!(
+            *localization.warned_missing_keys.lock().unwrap(),
+            HashSet::from(["ui.does_not_exist".to_string()])
+        );
+
+        localization.set_missing_key_policy(MissingKeyPolicy::Empty);
+        assert_eq!(localization.get("ui", "does_not_exist"), "");
+
+        localization.set_missing_key_policy(MissingKeyPolicy::FallbackKey);
+        assert_eq!(
+            localization.get("messages", "does_not_exist"),
+            "[messages.does_not_exist]"
+        );
+        assert_eq!(
+            localization.get("ui", "add_api_endpoint"),
+            "Add API endpoint"
+        );
+    }
+
+    #[test]
+    fn normalizes_language_tags() {
+        assert_eq!(normalize_language_tag("EN_us"), vec!["en-US", "en"]);
+        assert_eq!(
+            normalize_language_tag("zh-hant-tw"),
+            vec!["zh-Hant-TW", "zh-Hant", "zh"]
+        );
+        assert_eq!(normalize_language_tag("es-419"), vec!["es-419", "es"]);
+        assert_eq!(normalize_language_tag("de-CH-1996"), vec!["de-CH", "de"]);
+        assert!(normalize_language_tag("").is_empty());
+    }
+
+    #[test]
+    fn locale_fallback_chain_shortens_tags() {
+        assert_eq!(locale_fallback_chain("pt-BR"), vec!["pt-BR", "pt", "en"]);
+        assert_eq!(
+            locale_fallback_chain("zh-Hant-TW"),
+            vec!["zh-Hant-TW", "zh-Hant", "zh", "en"]
+        );
+        assert_eq!(locale_fallback_chain("en-US"), vec!["en-US", "en"]);
+        assert_eq!(locale_fallback_chain("en"), vec!["en"]);
+    }
+
+    #[test]
+    fn failed_reload_keeps_current_texts() {
+        let mut localization = Localization::new("fr").unwrap();
+        let settings_title = localization.ui("settings_title").to_string();
+
+        assert!(matches!(
+            localization.reload("xx-invalid"),
+            Err(RextTuiError::LanguageNotFound(_))
+        ));
+        assert_eq!(localization.ui("settings_title"), settings_title);
+        assert_eq!(localization.current_lang, "fr");
+
+        // Regional variants still fall back to their parent language
+        localization.reload("fr-CA").unwrap();
+        assert_eq!(localization.ui("settings_title"), settings_title);
+    }
+
+    #[test]
+    fn regional_language_falls_back_to_parent() {
+        let localization = Localization::new("fr-CA").unwrap();
+        let french = Localization::new("fr").unwrap();
+        assert_eq!(
+            localization.ui("settings_title"),
+            french.ui("settings_title")
+        );
+    }
+
+    #[test]
+    fn parse_space_aliases() {
+        let expected = Some((KeyModifiers::NONE, KeyCode::Char(' ')));
+        assert_eq!(Localization::parse_key_string("Space"), expected);
+        assert_eq!(Localization::parse_key_string("space"), expected);
+        assert_eq!(Localization::parse_key_string("SPACEBAR"), expected);
+    }
+
+    #[test]
+    fn invalid_key_strings_are_warned_once() {
+        assert_eq!(Localization::parse_key_string("Ctrl+Nope"), None);
+        assert_eq!(Localization::parse_key_string("Ctrl+Nope"), None);
+        assert!(!first_warning(&WARNED_KEY_STRINGS, "Ctrl+Nope".to_string()));
+    }
+
+    #[cfg(feature = "gettext")]
+    #[test]
+    fn po_file_to_localized_texts() {
+        let path = std::env::temp_dir().join(format!("rext-tui-test-{}.po", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"msgid ""
+msgstr ""
+"Project-Id-Version: rext-tui\n"
+"POT-Creation-Date: 2025-01-01 00:00+0000\n"
+"PO-Revision-Date: 2025-01-01 00:00+0000\n"
+"Language-Team: French\n"
+"MIME-Version: 1.0\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"Content-Transfer-Encoding: 8bit\n"
+"Language: fr\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+#. section: ui
+msgid "settings_title"
+msgstr "Paramètres"
+
+msgid "theme_setting"
+msgstr "Thème"
+
+#. section: messages
+msgid "config_reloaded"
+msgstr "Configuration rechargée"
+
+#. section: keys
+msgid "quit"
+msgstr "Ctrl+Q"
+
+msgid "untranslated"
+msgstr ""
+"#,
+        )
+        .unwrap();
+
+        let texts = LocalizedTexts::from_po_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(texts.ui["settings_title"], "Paramètres");
+        assert_eq!(texts.ui["theme_setting"], "Thème");
+        assert_eq!(texts.messages["config_reloaded"], "Configuration rechargée");
+        assert_eq!(texts.keys["quit"], "Ctrl+Q");
+        assert!(!texts.keys.contains_key("untranslated"));
+    }
+
+    #[test]
+    fn platform_key_overrides() {
+        let texts: LocalizedTexts = toml::from_str(
+            r#"
+            [ui]
+            [messages]
+
+            [keys]
+            quit = "q"
+            settings = "s"
+
+            [keys.overrides.macos]
+            quit = "Alt+Q"
+
+            [keys.overrides.linux]
+            quit = "Ctrl+Q"
+
+            [keys.overrides.windows]
+            quit = "F4"
+            "#,
+        )
+        .unwrap();
+
+        let expected = match current_platform() {
+            "macos" => "Alt+Q",
+            "windows" => "F4",
+            _ => "Ctrl+Q",
+        };
+        assert_eq!(texts.keys["quit"], expected);
+        assert_eq!(texts.keys["settings"], "s");
+        assert!(!texts.keys.contains_key("overrides"));
+    }
 }
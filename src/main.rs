@@ -1,8 +1,62 @@
-use rext_tui::{App, error::RextTuiError};
+use rext_tui::{
+    AppBuilder,
+    config::{get_rext_config_dir, load_current_language},
+    error::RextTuiError,
+    localization::Localization,
+};
+
+/// Loads the localization for messages printed outside the TUI
+fn cli_localization() -> Option<Localization> {
+    let language = load_current_language().unwrap_or_else(|_| "en".to_string());
+    Localization::new(&language).ok()
+}
+
+/// Sends the logs to debug.log in the config directory, stderr would draw over the TUI
+///
+/// Logging stays off if the file can't be opened.
+fn init_file_logger() {
+    let Ok(config_dir) = get_rext_config_dir() else {
+        return;
+    };
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(config_dir.debug_log_path());
+    if let Ok(file) = file {
+        env_logger::Builder::from_default_env()
+            .target(env_logger::Target::Pipe(Box::new(file)))
+            .init();
+    }
+}
 
 fn main() -> Result<(), RextTuiError> {
-    let terminal = ratatui::init();
-    let result = App::new().run(terminal);
+    // Handle the CLI flags before the terminal is put in raw mode
+    if std::env::args().any(|a| a == "--version" || a == "-V") {
+        println!("rext-tui {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+    if std::env::args().any(|a| a == "--help" || a == "-h") {
+        if let Some(localization) = cli_localization() {
+            println!("{}", localization.msg("cli_usage"));
+        }
+        return Ok(());
+    }
+
+    // Only enable the logs when asked for with RUST_LOG
+    if std::env::var_os("RUST_LOG").is_some() {
+        init_file_logger();
+    }
+
+    let result = AppBuilder::new()
+        .with_saved_session()
+        .build()
+        .run(ratatui::init());
     ratatui::restore();
-    result
+    if let Err(RextTuiError::AlreadyRunning) = result {
+        if let Some(localization) = cli_localization() {
+            eprintln!("{}", localization.msg("already_running"));
+        }
+        std::process::exit(1);
+    }
+    result.map(|_| ())
 }
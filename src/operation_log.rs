@@ -0,0 +1,114 @@
+//! # Operation Log
+//!
+//! A record of every rext-core operation run in the session (scaffolding, destroying,
+//! generating entities, undoing), kept in memory by the [`App`](crate::App) and appended
+//! to `~/.rext/tui.log` for review after the session, one JSON object per line:
+//!
+//! ```json
+//! {"timestamp": "1760572800", "operation": "scaffold_app", "success": true, "detail": "my-app"}
+//! ```
+
+use crate::error::RextTuiError;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum number of operation records kept in memory, the oldest are dropped first
+pub const MAX_OPERATION_RECORDS: usize = 1000;
+
+/// A rext-core operation and its outcome
+///
+/// - `timestamp`: When the operation ran, in seconds since the Unix epoch
+/// - `operation`: A snake_case operation name (e.g. `"destroy_app"`)
+/// - `success`: Whether the operation succeeded
+/// - `detail`: What the operation acted on if it succeeded, the error otherwise
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationRecord {
+    pub timestamp: String,
+    pub operation: String,
+    pub success: bool,
+    pub detail: String,
+}
+
+impl OperationRecord {
+    /// Creates a record of an operation that just finished
+    pub fn now(operation: &str, success: bool, detail: String) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self {
+            timestamp: timestamp.to_string(),
+            operation: operation.to_string(),
+            success,
+            detail,
+        }
+    }
+
+    /// The record as a single line JSON object
+    pub fn to_json_line(&self) -> String {
+        format!(
+            "{{\"timestamp\": \"{}\", \"operation\": \"{}\", \"success\": {}, \"detail\": \"{}\"}}",
+            json_escape(&self.timestamp),
+            json_escape(&self.operation),
+            self.success,
+            json_escape(&self.detail)
+        )
+    }
+
+    /// Appends the record as a JSON line to the log file at `path`
+    pub fn append_to(&self, path: &Path) -> Result<(), RextTuiError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(RextTuiError::WriteConfigFile)?;
+        writeln!(file, "{}", self.to_json_line()).map_err(RextTuiError::WriteConfigFile)
+    }
+}
+
+/// Escapes `text` for use inside a JSON string, details can hold arbitrary error messages
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_append_as_escaped_json_lines() {
+        let path = std::env::temp_dir().join(format!("rext_tui_ops_{}.log", std::process::id()));
+        OperationRecord::now("scaffold_app", true, "my-app".to_string())
+            .append_to(&path)
+            .unwrap();
+        OperationRecord::now("destroy_app", false, "no \"Cargo.toml\"\n\\".to_string())
+            .append_to(&path)
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("{\"timestamp\": \""));
+        assert!(lines[0].ends_with(
+            "\"operation\": \"scaffold_app\", \"success\": true, \"detail\": \"my-app\"}"
+        ));
+        assert!(
+            lines[1].ends_with("\"success\": false, \"detail\": \"no \\\"Cargo.toml\\\"\\n\\\\\"}")
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+//! # Local Usage Telemetry
+//!
+//! Opt-in counts of the actions used in the TUI, for the user's own insight.
+//! Nothing is sent anywhere, actions are appended to `~/.rext/telemetry.jsonl`
+//! as newline-delimited JSON, one object per action:
+//!
+//! ```json
+//! {"ts": 1760572800, "action": "open_api_endpoint"}
+//! ```
+//!
+//! Recording only starts after the user accepts the consent dialog shown on first run,
+//! the choice is saved as `telemetry_enabled` in preferences.toml.
+
+use crate::error::RextTuiError;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Appends user actions to the local telemetry file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TelemetryRecorder {
+    path: PathBuf,
+}
+
+impl TelemetryRecorder {
+    /// Creates a recorder appending to the file at `path`
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Appends `{"ts": <unix time>, "action": "<action>"}` to the telemetry file
+    ///
+    /// `action` is a snake_case identifier (e.g. `"create_endpoint"`), never user input.
+    pub fn record(&self, action: &str) -> Result<(), RextTuiError> {
+        let ts = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(RextTuiError::WriteConfigFile)?;
+        writeln!(file, "{{\"ts\": {}, \"action\": \"{}\"}}", ts, action)
+            .map_err(RextTuiError::WriteConfigFile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_appends_json_lines() {
+        let path =
+            std::env::temp_dir().join(format!("rext_tui_telemetry_{}.jsonl", std::process::id()));
+        let recorder = TelemetryRecorder::new(path.clone());
+        recorder.record("open_api_endpoint").unwrap();
+        recorder.record("create_endpoint").unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("{\"ts\": "));
+        assert!(lines[0].ends_with(", \"action\": \"open_api_endpoint\"}"));
+        assert!(lines[1].ends_with(", \"action\": \"create_endpoint\"}"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,12 +1,38 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 use std::io;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-// Import the App struct from the main crate
-use rext_tui::App;
+// Import the commonly used types from the main crate
+use rext_tui::MockRextCore;
+use rext_tui::prelude::*;
+
+/// A fresh config directory in the system temp directory, so tests don't touch ~/.rext/
+fn temp_config_dir() -> RextConfigDir {
+    static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+    let index = NEXT_DIR.fetch_add(1, Ordering::Relaxed);
+    let path = std::env::temp_dir().join(format!(
+        "rext_tui_integration_{}_{}",
+        std::process::id(),
+        index
+    ));
+    let _ = std::fs::remove_dir_all(&path);
+    RextConfigDir::new(path).unwrap()
+}
+
+/// An app with its own config directory, see [`temp_config_dir`]
+fn test_app() -> App {
+    AppBuilder::new().config_dir(temp_config_dir()).build()
+}
 
 #[test]
 fn handle_key_event() -> io::Result<()> {
-    let mut app = App::new();
+    let mut app = test_app();
 
     // Test right key increments counter
     let right_event = KeyEvent::from(KeyCode::Right);
@@ -19,7 +45,7 @@ fn handle_key_event() -> io::Result<()> {
     app.on_key_event(left_event);
 
     // Test quit functionality
-    let mut app = App::new();
+    let mut app = test_app();
     let quit_event = KeyEvent::from(KeyCode::Char('q'));
     app.on_key_event(quit_event);
     // Since running field is private, we can't directly check it
@@ -27,3 +53,678 @@ fn handle_key_event() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn on_key_event_reports_consumed_keys() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::None;
+
+    assert!(!app.on_key_event(KeyEvent::from(KeyCode::Char('z'))));
+    assert!(app.on_key_event(KeyEvent::from(KeyCode::Char('q'))));
+
+    // Typed text is consumed by dialogs with an input
+    app.open_api_endpoint_dialog();
+    assert!(app.on_key_event(KeyEvent::from(KeyCode::Char('z'))));
+    assert!(!app.on_key_event(KeyEvent::from(KeyCode::F(5))));
+}
+
+#[test]
+fn version_flag_prints_version_without_tui() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rext-tui"))
+        .arg("--version")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        format!("rext-tui {}", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn open_and_close_dialogs() {
+    let mut app = test_app();
+
+    app.open_settings_dialog();
+    assert_eq!(app.current_dialog, DialogType::Settings);
+
+    app.open_language_selection_dialog();
+    assert_eq!(app.current_dialog, DialogType::Language);
+
+    app.open_api_endpoint_dialog();
+    assert_eq!(app.current_dialog, DialogType::ApiEndpoint);
+
+    app.close_current_dialog();
+    assert_eq!(app.current_dialog, DialogType::None);
+}
+
+#[test]
+fn dialog_change_hook_records_transitions() {
+    let transitions = Rc::new(RefCell::new(Vec::new()));
+    let recorded = Rc::clone(&transitions);
+    let mut app = AppBuilder::new()
+        .config_dir(temp_config_dir())
+        .on_dialog_change(move |old, new| recorded.borrow_mut().push((old.clone(), new.clone())))
+        .build();
+
+    app.open_settings_dialog();
+    app.open_language_selection_dialog();
+    app.close_current_dialog();
+
+    assert_eq!(
+        *transitions.borrow(),
+        vec![
+            (DialogType::None, DialogType::Settings),
+            (DialogType::Settings, DialogType::Language),
+            (DialogType::Language, DialogType::None),
+        ]
+    );
+}
+
+#[test]
+fn app_restores_snapshot() {
+    let snapshot = AppSnapshot {
+        theme: "nord".to_string(),
+        language: "fr".to_string(),
+        last_dialog: "settings".to_string(),
+    };
+    let app = AppBuilder::from_snapshot(snapshot.clone())
+        .config_dir(temp_config_dir())
+        .build();
+
+    assert_eq!(app.current_theme, "nord");
+    assert_eq!(app.current_dialog, DialogType::Settings);
+    assert_eq!(app.snapshot(), snapshot);
+}
+
+/// A mock with an existing Rext app, failing destroys and 3 generated entities
+fn mock_rext_core() -> Box<MockRextCore> {
+    Box::new(MockRextCore {
+        app_exists: Rc::new(Cell::new(true)),
+        destroy_error: Some("mock destroy failure".to_string()),
+        entity_count: 3,
+        ..MockRextCore::default()
+    })
+}
+
+#[test]
+fn destroy_app_error_uses_mock_rext_core() {
+    let mut app = test_app().with_mock_rext_core(mock_rext_core());
+    app.open_settings_dialog();
+
+    // Select "Destroy Rext app", the fourth settings option
+    for _ in 0..3 {
+        app.on_key_event(KeyEvent::from(KeyCode::Down));
+    }
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(
+        app.current_dialog,
+        DialogType::ConfirmDestroy { undo: false }
+    );
+
+    // Nothing is destroyed until it's confirmed, No is focused first
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.current_dialog, DialogType::Settings);
+    assert!(app.operation_log().is_empty());
+
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    app.on_key_event(KeyEvent::from(KeyCode::Left));
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.current_dialog, DialogType::Settings);
+
+    let message = app
+        .new_app_message
+        .as_deref()
+        .expect("destroy should set a message");
+    assert!(message.contains("mock destroy failure"));
+
+    let log = app.operation_log();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].operation, "destroy_app");
+    assert!(!log[0].success);
+    assert_eq!(log[0].detail, "mock destroy failure");
+
+    // The operation is also appended to tui.log in the app's config directory
+    let log_path = app.config_dir.as_ref().unwrap().operation_log_path();
+    let written = std::fs::read_to_string(log_path).unwrap();
+    assert_eq!(written.lines().count(), 1);
+    assert!(written.contains("\"destroy_app\""));
+}
+
+#[test]
+fn settings_position_is_restored_on_reopen() {
+    let mut app = test_app();
+    app.open_settings_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::Down));
+    app.on_key_event(KeyEvent::from(KeyCode::Down));
+    app.close_current_dialog();
+    assert_eq!(app.settings_selected, 0);
+
+    app.open_settings_dialog();
+    assert_eq!(app.settings_selected, 2);
+    assert_eq!(app.saved_scroll_positions.get("settings"), Some(&2));
+}
+
+#[test]
+fn new_app_name_input_requires_a_name_before_buttons() {
+    let mut app = test_app().with_mock_rext_core(mock_rext_core());
+    app.current_dialog = DialogType::NewApp;
+    assert_eq!(app.new_app_name_input, app.workspace.dir_name);
+    assert!(app.new_app_name_focused);
+
+    app.new_app_name_input.clear();
+    app.on_key_event(KeyEvent::from(KeyCode::Tab));
+    assert!(app.new_app_name_focused);
+
+    for c in "my/app".chars() {
+        app.on_key_event(KeyEvent::from(KeyCode::Char(c)));
+    }
+    app.on_key_event(KeyEvent::from(KeyCode::Tab));
+    assert!(app.new_app_name_focused);
+
+    for _ in 0..4 {
+        app.on_key_event(KeyEvent::from(KeyCode::Backspace));
+    }
+    app.on_key_event(KeyEvent::from(KeyCode::Char('_')));
+    app.on_key_event(KeyEvent::from(KeyCode::Tab));
+    assert!(!app.new_app_name_focused);
+    assert_eq!(app.new_app_name_input, "my_");
+}
+
+#[test]
+fn dialog_stats_count_opens() {
+    let mut app = test_app();
+    app.open_settings_dialog();
+    app.close_current_dialog();
+    app.open_settings_dialog();
+    app.open_language_selection_dialog();
+
+    assert_eq!(app.dialog_stats().get("settings"), Some(&2));
+    assert_eq!(app.dialog_stats().get("language"), Some(&1));
+    assert_eq!(app.dialog_stats().get("none"), None);
+}
+
+#[test]
+fn generate_entities_reports_entity_count() {
+    let mut app = test_app().with_mock_rext_core(mock_rext_core());
+    app.on_key_event(KeyEvent::from(KeyCode::Char('g')));
+
+    let notification = app
+        .notifications
+        .last()
+        .expect("generating should show a notification");
+    assert!(notification.message.contains("Generated 3 entities"));
+    assert!(!notification.is_error);
+}
+
+#[test]
+fn generate_entities_failure_is_an_error_notification() {
+    let mut app = test_app().with_mock_rext_core(Box::new(MockRextCore {
+        app_exists: Rc::new(Cell::new(true)),
+        generate_error: Some("no database".to_string()),
+        ..MockRextCore::default()
+    }));
+    app.current_dialog = DialogType::None;
+    app.on_key_event(KeyEvent::from(KeyCode::Char('g')));
+
+    let notification = app.notifications.last().unwrap();
+    assert!(notification.message.contains("no database"));
+    assert!(notification.is_error);
+}
+
+#[test]
+fn settings_digit_selects_and_activates_option() {
+    let mut app = test_app();
+    app.open_settings_dialog();
+
+    app.on_key_event(KeyEvent::from(KeyCode::Char('5')));
+    assert_eq!(app.current_dialog, DialogType::None);
+
+    app.open_settings_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::Char('4')));
+    assert_eq!(
+        app.current_dialog,
+        DialogType::ConfirmDestroy { undo: false }
+    );
+    app.on_key_event(KeyEvent::from(KeyCode::Esc));
+    assert_eq!(app.current_dialog, DialogType::Settings);
+
+    // Digits past the last option do nothing
+    assert!(!app.on_key_event(KeyEvent::from(KeyCode::Char('7'))));
+    assert_eq!(app.current_dialog, DialogType::Settings);
+    app.close_current_dialog();
+
+    app.open_settings_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::Char('2')));
+    assert_eq!(app.current_dialog, DialogType::Language);
+}
+
+#[test]
+fn session_duration_increases() {
+    let app = test_app();
+    let first = app.session_duration();
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    assert!(app.session_duration() > first);
+}
+
+#[test]
+fn startup_duration_is_measured_once() {
+    let app = test_app();
+    let startup = app.startup_duration();
+    assert!(startup > std::time::Duration::ZERO);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    assert_eq!(app.startup_duration(), startup);
+}
+
+#[test]
+fn paste_inserts_first_line_into_active_input() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::ApiEndpoint;
+    app.on_paste("users\nposts");
+    assert_eq!(app.api_endpoint_input, "users");
+
+    app.current_dialog = DialogType::None;
+    app.on_paste("ignored");
+    assert_eq!(app.api_endpoint_input, "users");
+}
+
+#[test]
+fn endpoint_history_is_per_project() {
+    let config_dir = temp_config_dir();
+    let app_in = |project: &str| {
+        AppBuilder::new()
+            .config_dir(config_dir.clone())
+            .working_dir(PathBuf::from(project))
+            .build()
+    };
+
+    let mut app = app_in("/tmp/first-project");
+    app.current_dialog = DialogType::ApiEndpoint;
+    app.api_endpoint_input = "users".to_string();
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.endpoint_history, ["users"]);
+    drop(app);
+
+    // Another project doesn't warn about the first project's endpoint
+    let mut app = app_in("/tmp/second-project");
+    assert!(app.endpoint_history.is_empty());
+    app.current_dialog = DialogType::ApiEndpoint;
+    app.api_endpoint_input = "users".to_string();
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert!(!app.api_endpoint_overwrite_confirmed);
+
+    assert_eq!(app_in("/tmp/first-project").endpoint_history, ["users"]);
+}
+
+#[test]
+fn existing_endpoint_needs_a_second_enter() {
+    let mut app = test_app();
+    app.endpoint_history = vec!["users".to_string()];
+    app.current_dialog = DialogType::ApiEndpoint;
+    app.api_endpoint_input = "users".to_string();
+
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.current_dialog, DialogType::ApiEndpoint);
+    assert!(app.api_endpoint_overwrite_confirmed);
+
+    // Editing the name asks again
+    app.on_key_event(KeyEvent::from(KeyCode::Char('s')));
+    assert!(!app.api_endpoint_overwrite_confirmed);
+}
+
+#[test]
+fn theme_and_language_names_match_config() {
+    let app = test_app();
+    assert_eq!(app.theme_names(), get_available_themes(None).unwrap());
+    assert_eq!(
+        app.language_names(),
+        get_available_languages_with_display().unwrap()
+    );
+}
+
+#[test]
+fn home_and_end_jump_to_list_ends() {
+    let mut app = test_app();
+    app.open_settings_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::End));
+    assert_eq!(
+        app.settings_selected,
+        SettingsOption::all_variants().len() - 1
+    );
+    app.on_key_event(KeyEvent::from(KeyCode::Home));
+    assert_eq!(app.settings_selected, 0);
+    app.close_current_dialog();
+
+    app.open_language_selection_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::End));
+    assert_eq!(app.language_selected, app.filtered_languages.len() - 1);
+    app.on_key_event(KeyEvent::from(KeyCode::Home));
+    assert_eq!(app.language_selected, 0);
+}
+
+#[test]
+fn page_keys_move_by_page_and_clamp() {
+    let mut app = test_app();
+    app.open_language_selection_dialog();
+    app.filtered_languages = (0..5)
+        .map(|i| (format!("l{i}"), format!("Language {i}")))
+        .collect();
+    app.language_list_page_size = 2;
+
+    app.on_key_event(KeyEvent::from(KeyCode::PageDown));
+    assert_eq!(app.language_selected, 2);
+    app.on_key_event(KeyEvent::from(KeyCode::PageDown));
+    app.on_key_event(KeyEvent::from(KeyCode::PageDown));
+    assert_eq!(app.language_selected, 4);
+
+    app.on_key_event(KeyEvent::from(KeyCode::PageUp));
+    assert_eq!(app.language_selected, 2);
+    app.on_key_event(KeyEvent::from(KeyCode::PageUp));
+    app.on_key_event(KeyEvent::from(KeyCode::PageUp));
+    assert_eq!(app.language_selected, 0);
+}
+
+#[test]
+fn undo_reverts_latest_operation() {
+    let mut app = test_app().with_mock_rext_core(mock_rext_core());
+    app.current_dialog = DialogType::None;
+    app.endpoint_history = vec!["get_users".to_string()];
+    app.undo_stack
+        .push(UndoAction::CreateEndpoint("get_users".to_string()));
+    app.undo_stack
+        .push(UndoAction::DestroyApp(PathBuf::from("/tmp/test-project")));
+
+    // Destroying the app asks first, cancelling keeps the undo step
+    let undo = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    app.on_key_event(undo);
+    assert_eq!(
+        app.current_dialog,
+        DialogType::ConfirmDestroy { undo: true }
+    );
+    app.on_key_event(KeyEvent::from(KeyCode::Esc));
+    assert_eq!(app.current_dialog, DialogType::None);
+    assert_eq!(app.undo_stack.len(), 2);
+    assert!(app.notifications.is_empty());
+
+    // The mock fails to destroy, then deletes the endpoint
+    app.on_key_event(undo);
+    app.on_key_event(KeyEvent::from(KeyCode::Left));
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.current_dialog, DialogType::None);
+    assert!(app.notifications.last().unwrap().is_error);
+
+    app.undo();
+    let notification = app.notifications.last().unwrap();
+    assert!(!notification.is_error);
+    assert_eq!(
+        notification.message,
+        "Undone: removed endpoint 'get_users' from the endpoint history"
+    );
+    assert!(app.endpoint_history.is_empty());
+    assert!(app.undo_stack.is_empty());
+}
+
+#[test]
+fn error_dialog_is_dismissed_with_enter() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::Error {
+        message: "Failed to write config file".to_string(),
+    };
+    app.last_error = Some("Failed to write config file".to_string());
+
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.current_dialog, DialogType::None);
+    assert_eq!(app.last_error, None);
+}
+
+#[test]
+fn generate_shortcut_generates_entities_in_the_workspace() {
+    let mock = mock_rext_core();
+    let calls = Rc::clone(&mock.calls);
+    let mut app = AppBuilder::new()
+        .config_dir(temp_config_dir())
+        .working_dir(PathBuf::from("/tmp/test-project"))
+        .build()
+        .with_mock_rext_core(mock);
+    app.current_dialog = DialogType::None;
+
+    assert!(app.on_key_event(KeyEvent::from(KeyCode::Char('g'))));
+    assert_eq!(app.current_dialog, DialogType::None);
+    assert_eq!(
+        calls.borrow().last(),
+        Some(&(
+            "generate_sea_orm_entities_with_open_api_schema".to_string(),
+            PathBuf::from("/tmp/test-project")
+        ))
+    );
+    assert_eq!(
+        app.notifications.last(),
+        Some(&Notification {
+            message: "Generated 3 entities in test-project".to_string(),
+            is_error: false,
+        })
+    );
+}
+
+#[test]
+fn working_dir_sets_the_workspace_name() {
+    let app = AppBuilder::new()
+        .config_dir(temp_config_dir())
+        .working_dir(PathBuf::from("/tmp/test-project"))
+        .build();
+    assert_eq!(app.workspace.dir_name, "test-project");
+    assert_eq!(app.new_app_name_input, "test-project");
+}
+
+#[test]
+fn sticky_language_search_survives_reopening() {
+    let mut app = test_app();
+    app.sticky_language_search = true;
+    app.open_language_selection_dialog();
+    app.on_paste("fr");
+    app.on_key_event(KeyEvent::from(KeyCode::Esc));
+
+    app.open_language_selection_dialog();
+    assert_eq!(app.language_search, "fr");
+    assert!(app.filtered_languages.iter().all(|(code, display)| {
+        code.to_lowercase().contains("fr") || display.to_lowercase().contains("fr")
+    }));
+    app.close_current_dialog();
+
+    app.sticky_language_search = false;
+    app.open_language_selection_dialog();
+    assert!(app.language_search.is_empty());
+}
+
+#[test]
+fn cycling_themes_records_one_recent_theme() {
+    let mut app = test_app();
+    app.open_settings_dialog();
+    for _ in 0..3 {
+        app.on_key_event(KeyEvent::from(KeyCode::Char('1')));
+    }
+    assert!(app.recently_used_themes.is_empty());
+
+    app.close_current_dialog();
+    assert_eq!(
+        app.recently_used_themes,
+        VecDeque::from([app.current_theme.clone()])
+    );
+}
+
+#[test]
+fn recent_themes_dialog_switches_theme() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::None;
+    app.recently_used_themes = VecDeque::from(["nord".to_string(), "dracula".to_string()]);
+
+    let ctrl_t = KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL);
+    assert!(app.on_key_event(ctrl_t));
+    assert_eq!(app.current_dialog, DialogType::RecentThemes);
+
+    app.on_key_event(KeyEvent::from(KeyCode::Down));
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+    assert_eq!(app.current_dialog, DialogType::None);
+    assert_eq!(app.current_theme, "dracula");
+    assert_eq!(
+        app.recently_used_themes,
+        VecDeque::from(["dracula".to_string(), "nord".to_string()])
+    );
+}
+
+#[test]
+fn recent_themes_are_persisted() {
+    let config_dir = temp_config_dir();
+    let mut app = AppBuilder::new().config_dir(config_dir.clone()).build();
+    app.open_settings_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::Char('1')));
+    app.close_current_dialog();
+    let recent = app.recently_used_themes.clone();
+
+    let app = AppBuilder::new().config_dir(config_dir).build();
+    assert_eq!(app.recently_used_themes, recent);
+}
+
+#[test]
+fn reload_works_without_saved_preferences() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::None;
+
+    let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+    app.on_key_event(ctrl_r);
+    let notification = app.notifications.last().unwrap();
+    assert!(!notification.is_error, "{}", notification.message);
+}
+
+#[test]
+fn notifications_are_capped() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::None;
+
+    let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+    for _ in 0..MAX_NOTIFICATIONS + 5 {
+        app.on_key_event(ctrl_r);
+    }
+    assert_eq!(app.notifications.len(), MAX_NOTIFICATIONS);
+}
+
+#[test]
+fn run_is_driven_by_the_event_source() {
+    let transitions = Rc::new(RefCell::new(Vec::new()));
+    let recorded = Rc::clone(&transitions);
+    // Popped from the back: open the settings, close them, then quit
+    let mut events = vec![
+        Event::Key(KeyEvent::from(KeyCode::Esc)),
+        Event::Key(KeyEvent::from(KeyCode::Esc)),
+        Event::Key(KeyEvent::from(KeyCode::Char('s'))),
+    ];
+    let mut app = AppBuilder::new()
+        .config_dir(temp_config_dir())
+        .on_dialog_change(move |old, new| recorded.borrow_mut().push((old.clone(), new.clone())))
+        .event_source(move || {
+            events
+                .pop()
+                .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))
+        })
+        .build()
+        .with_mock_rext_core(Box::new(MockRextCore {
+            app_exists: Rc::new(Cell::new(true)),
+            ..MockRextCore::default()
+        }));
+    app.telemetry_prompted = true;
+
+    let terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    assert_eq!(app.run(terminal).ok(), Some(AppExitReason::Quit));
+    assert_eq!(
+        *transitions.borrow(),
+        vec![
+            (DialogType::None, DialogType::Settings),
+            (DialogType::Settings, DialogType::None),
+        ]
+    );
+}
+
+#[test]
+fn goto_top_sequence_works_in_list_dialogs() {
+    let mut app = test_app();
+    let ctrl_k = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+
+    app.open_settings_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::Down));
+    app.on_key_event(KeyEvent::from(KeyCode::Down));
+    assert!(app.on_key_event(ctrl_k));
+    assert!(app.on_key_event(ctrl_k));
+    assert_eq!(app.settings_selected, 0);
+    app.close_current_dialog();
+
+    app.open_language_selection_dialog();
+    app.on_key_event(KeyEvent::from(KeyCode::Down));
+    app.on_key_event(ctrl_k);
+    app.on_key_event(ctrl_k);
+    assert_eq!(app.language_selected, 0);
+
+    // Typed characters still go to the search input
+    app.on_key_event(KeyEvent::from(KeyCode::Char('g')));
+    app.on_key_event(KeyEvent::from(KeyCode::Char('g')));
+    assert_eq!(app.language_search, "gg");
+}
+
+#[test]
+fn sea_orm_config_dialog_edits_both_inputs() {
+    let mut app = test_app();
+    app.current_dialog = DialogType::None;
+
+    assert!(app.on_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL)));
+    assert_eq!(app.current_dialog, DialogType::SeaOrmConfig);
+    assert_eq!(app.sea_orm_output_dir_input, DEFAULT_SEA_ORM_OUTPUT_DIR);
+
+    app.sea_orm_database_url_input.clear();
+    app.on_paste("sqlite://rext.db");
+    assert_eq!(app.sea_orm_database_url_input, "sqlite://rext.db");
+
+    app.on_key_event(KeyEvent::from(KeyCode::Tab));
+    app.on_key_event(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    app.on_key_event(KeyEvent::from(KeyCode::Char('e')));
+    assert_eq!(app.sea_orm_output_dir_input, "e");
+    assert_eq!(app.sea_orm_database_url_input, "sqlite://rext.db");
+
+    app.on_key_event(KeyEvent::from(KeyCode::Esc));
+    assert_eq!(app.current_dialog, DialogType::None);
+}
+
+#[test]
+fn sea_orm_config_is_saved_before_generating() {
+    let project_dir = temp_config_dir().path().to_path_buf();
+    let mock = mock_rext_core();
+    let calls = Rc::clone(&mock.calls);
+    let mut app = AppBuilder::new()
+        .config_dir(temp_config_dir())
+        .working_dir(project_dir.clone())
+        .build()
+        .with_mock_rext_core(mock);
+    app.current_dialog = DialogType::None;
+
+    app.open_sea_orm_config_dialog();
+    app.on_paste("sqlite://rext.db");
+    app.on_key_event(KeyEvent::from(KeyCode::Enter));
+
+    assert_eq!(app.current_dialog, DialogType::None);
+    assert_eq!(
+        load_sea_orm_config_in(&project_dir).unwrap(),
+        SeaOrmConfig {
+            database_url: "sqlite://rext.db".to_string(),
+            output_dir: DEFAULT_SEA_ORM_OUTPUT_DIR.to_string(),
+        }
+    );
+    assert_eq!(
+        calls.borrow().last(),
+        Some(&(
+            "generate_sea_orm_entities_with_open_api_schema".to_string(),
+            project_dir
+        ))
+    );
+
+    // Reopening the dialog shows the saved config
+    app.open_sea_orm_config_dialog();
+    assert_eq!(app.sea_orm_database_url_input, "sqlite://rext.db");
+}